@@ -0,0 +1,312 @@
+use crate::Style::{display_width, Style, StyleBlock};
+use std::sync::Arc;
+
+/// Marker of the item at `index`, counted from 0, e.g. "•" or "3."
+pub type Enumerator = Arc<dyn Fn(usize) -> String + Send + Sync>;
+
+/// Style of the enumerator at `index`
+pub type EnumeratorStyleFunc = Arc<dyn Fn(usize) -> Style + Send + Sync>;
+
+/// Style of the item at `index`, its text and width are set by the list
+pub type ItemStyleFunc = Arc<dyn Fn(usize) -> StyleBlock + Send + Sync>;
+
+/// • Bullet
+pub fn bullet(_index: usize) -> String {
+    return "•".to_owned();
+}
+
+/// - Dash
+pub fn dash(_index: usize) -> String {
+    return "-".to_owned();
+}
+
+/// * Asterisk
+pub fn asterisk(_index: usize) -> String {
+    return "*".to_owned();
+}
+
+/// 1. 2. 3.
+pub fn arabic(index: usize) -> String {
+    return format!("{}.", index + 1);
+}
+
+/// A. B. C. ... Z. AA. AB.
+pub fn alphabet(index: usize) -> String {
+    let mut letters = Vec::new();
+    let mut rest = index + 1;
+    while rest > 0 {
+        rest -= 1;
+        letters.push((b'A' + (rest % 26) as u8) as char);
+        rest /= 26;
+    }
+    let mut marker: String = letters.into_iter().rev().collect();
+    marker.push('.');
+    return marker;
+}
+
+/// I. II. III. IV.
+pub fn roman(index: usize) -> String {
+    const NUMERALS: [(usize, &str); 13] = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+    let mut marker = String::new();
+    let mut rest = index + 1;
+    for (value, numeral) in NUMERALS {
+        while rest >= value {
+            marker.push_str(numeral);
+            rest -= value;
+        }
+    }
+    marker.push('.');
+    return marker;
+}
+
+/// Entry of a list
+#[derive(Clone)]
+pub enum Item {
+    /// Text rendered with the item style
+    Text(String),
+    /// Block rendered as is, with its own style
+    Block(Box<StyleBlock>),
+    /// List nested under the previous item, not enumerated itself
+    List(List),
+}
+
+impl From<&str> for Item {
+    fn from(text: &str) -> Self {
+        return Item::Text(text.to_owned());
+    }
+}
+
+impl From<String> for Item {
+    fn from(text: String) -> Self {
+        return Item::Text(text);
+    }
+}
+
+impl From<StyleBlock> for Item {
+    fn from(block: StyleBlock) -> Self {
+        return Item::Block(Box::new(block));
+    }
+}
+
+impl From<List> for Item {
+    fn from(list: List) -> Self {
+        return Item::List(list);
+    }
+}
+
+/// Enumerated list of items
+///
+/// Enumerators are aligned to the right and followed by a space.
+/// Items spanning several lines, because they wrap or are multi-line blocks,
+/// are indented under their first line
+///
+/// # Example
+///
+/// ```
+/// # use ripgross::List::*;
+/// let list = List::new()
+///     .enumerator(arabic)
+///     .item("Fruits")
+///     .item(List::new().items(&["Apple", "Banana"]))
+///     .item("Vegetables");
+/// let expected = ["1. Fruits", "   • Apple", "   • Banana", "2. Vegetables"];
+/// assert_eq!(list.render(), expected.join("\n"));
+/// ```
+#[derive(Clone)]
+pub struct List {
+    items: Vec<Item>,
+    enumerator: Enumerator,
+    enumerator_style: EnumeratorStyleFunc,
+    item_style: ItemStyleFunc,
+    width: Option<usize>,
+}
+
+impl List {
+    /// Create an empty bullet list
+    pub fn new() -> List {
+        return List {
+            items: Vec::new(),
+            enumerator: Arc::new(bullet),
+            enumerator_style: Arc::new(|_| Style::new()),
+            item_style: Arc::new(|_| StyleBlock::new()),
+            width: None,
+        };
+    }
+    /// Append an item: text, a block or a nested list
+    pub fn item(mut self, item: impl Into<Item>) -> Self {
+        self.items.push(item.into());
+        return self;
+    }
+    /// Append several text items
+    pub fn items<S: AsRef<str>>(mut self, items: &[S]) -> Self {
+        for item in items {
+            self = self.item(item.as_ref());
+        }
+        return self;
+    }
+    /// Set the enumerator, one of `bullet`, `dash`, `asterisk`, `arabic`, `alphabet`, `roman`
+    /// or any function of the item index
+    pub fn enumerator(
+        mut self,
+        enumerator: impl Fn(usize) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.enumerator = Arc::new(enumerator);
+        return self;
+    }
+    /// Set the style of every enumerator
+    pub fn enumerator_style(self, style: &Style) -> Self {
+        let style = style.clone();
+        return self.enumerator_style_func(move |_| style.clone());
+    }
+    /// Set the style of the enumerator at each index
+    pub fn enumerator_style_func(
+        mut self,
+        style_func: impl Fn(usize) -> Style + Send + Sync + 'static,
+    ) -> Self {
+        self.enumerator_style = Arc::new(style_func);
+        return self;
+    }
+    /// Set the style of every text item
+    pub fn item_style(self, style: &StyleBlock) -> Self {
+        let style = style.clone();
+        return self.item_style_func(move |_| style.clone());
+    }
+    /// Set the style of the text item at each index
+    pub fn item_style_func(
+        mut self,
+        style_func: impl Fn(usize) -> StyleBlock + Send + Sync + 'static,
+    ) -> Self {
+        self.item_style = Arc::new(style_func);
+        return self;
+    }
+    /// Set the width of the whole list, text items wrap within it after the enumerator
+    pub fn set_width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        return self;
+    }
+    /// Render the list to a string, without trailing newline
+    pub fn render(&self) -> String {
+        return self.render_lines().join("\n");
+    }
+    /// Render the list to a block, to be joined or placed with other blocks
+    pub fn to_block(&self) -> StyleBlock {
+        return StyleBlock::from_lines(self.render_lines());
+    }
+    fn render_lines(&self) -> Vec<String> {
+        let enumerated = self
+            .items
+            .iter()
+            .filter(|item| !matches!(item, Item::List(_)))
+            .count();
+        let markers: Vec<String> = (0..enumerated)
+            .map(|index| (self.enumerator)(index))
+            .collect();
+        let marker_width = markers
+            .iter()
+            .map(|marker| display_width(marker))
+            .max()
+            .unwrap_or(0);
+        // Nothing to enumerate, nested lists are not indented
+        let indent = if enumerated == 0 { 0 } else { marker_width + 1 };
+        let item_width = self
+            .width
+            .map(|list_width| list_width.saturating_sub(indent));
+        let mut lines = Vec::new();
+        let mut index = 0;
+        for item in self.items.iter() {
+            let item_lines = match item {
+                Item::List(list) => {
+                    let mut list = list.clone();
+                    if let Some(item_width) = item_width {
+                        list = list.set_width(item_width);
+                    }
+                    for line in list.render_lines() {
+                        lines.push(format!("{}{}", " ".repeat(indent), line));
+                    }
+                    continue;
+                }
+                Item::Block(block) => block.render_lines(),
+                Item::Text(text) => self.text_lines(index, text, item_width),
+            };
+            let marker = &markers[index];
+            let mut prefix = " ".repeat(marker_width - display_width(marker));
+            prefix.push_str(&(self.enumerator_style)(index).render(marker));
+            prefix.push(' ');
+            for (row, line) in item_lines.into_iter().enumerate() {
+                if row == 0 {
+                    lines.push(format!("{}{}", prefix, line));
+                } else {
+                    lines.push(format!("{}{}", " ".repeat(indent), line));
+                }
+            }
+            index += 1;
+        }
+        return lines;
+    }
+    /// Lines of a text item, wrapped to `item_width` when the list width is set
+    fn text_lines(&self, index: usize, text: &str, item_width: Option<usize>) -> Vec<String> {
+        let style = (self.item_style)(index).set_string(text);
+        return match item_width {
+            Some(item_width) => {
+                let frame = style.get_horizontal_border_size() + style.get_horizontal_margins();
+                let padding = style.get_horizontal_padding();
+                let block_width = item_width.saturating_sub(frame).max(padding + 1);
+                style.set_width(block_width).render_lines()
+            }
+            None => style.render_lines(),
+        };
+    }
+}
+
+impl Default for List {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enumerate_items() {
+        assert_eq!(alphabet(0), "A.");
+        assert_eq!(alphabet(27), "AB.");
+        assert_eq!(roman(13), "XIV.");
+        let list = List::new()
+            .enumerator(roman)
+            .items(&["one", "two", "three", "four"]);
+        let expected = ["  I. one", " II. two", "III. three", " IV. four"];
+        assert_eq!(list.render(), expected.join("\n"));
+    }
+
+    #[test]
+    fn hanging_indent() {
+        let list = List::new()
+            .enumerator(dash)
+            .item("a long item that wraps")
+            .set_width(12);
+        let expected = ["- a long    ", "  item that ", "  wraps     "];
+        assert_eq!(list.render(), expected.join("\n"));
+    }
+
+    #[test]
+    fn nested_list_without_items() {
+        let list = List::new().item(List::new().items(&["x"]));
+        assert_eq!(list.render(), "• x");
+    }
+}
@@ -1,26 +1,47 @@
+pub use border::BorderStyle;
+pub(crate) use border::{edge_width, fit_glyph, horizontal_border, horizontal_edge, vertical_edge};
+pub use color::{AdaptiveColor, ColorError, CompleteAdaptiveColor, CompleteColor, TerminalColor};
+pub use join::{join_horizontal, join_vertical};
+pub(crate) use measure::{display_width, expand_tabs, widest_grapheme};
+pub use measure::{height, size, truncate, width};
 use nu_ansi_term;
-use nu_ansi_term::Color;
-use std::collections::{HashMap, HashSet};
+pub use place::{place, place_horizontal, place_vertical, Whitespace};
+pub use position::Position;
+pub use profile::ColorProfile;
+pub use renderer::{
+    color_profile, default_renderer, has_dark_background, set_color_profile, set_default_renderer,
+    set_has_dark_background, Renderer,
+};
+use std::collections::HashMap;
 
 #[derive(Debug, Clone)]
 pub struct Style {
-    decorator_dict: HashSet<Decorator>,
-    decorated_space: bool,
-    background_color: Option<Color>,
-    foreground_color: Option<Color>,
+    decorator_dict: HashMap<Decorator, bool>,
+    decorated_space: Option<bool>,
+    background_color: Option<TerminalColor>,
+    foreground_color: Option<TerminalColor>,
+    renderer: Option<Renderer>,
 }
 
 #[derive(Debug, Clone)]
 pub struct StyleBlock {
-    width: usize,
-    height: usize,
+    width: Option<usize>,
+    height: Option<usize>,
+    vertical_alignment: Option<Alignment>,
+    height_ellipsis: Option<String>,
     raw_string: String,
     content: Vec<String>,
     style: Style,
-    horizontal_alignment: Alignment,
+    horizontal_alignment: Option<Alignment>,
     layout_dict: HashMap<Layout, usize>,
-    border_dict: HashSet<Border>,
+    border_dict: HashMap<Border, bool>,
+    border_style: Option<BorderStyle>,
+    border_decoration_dict: HashMap<Border, Style>,
     paragraph_fixed: bool,
+    margin_background: Option<TerminalColor>,
+    color_whitespace: Option<bool>,
+    max_width: Option<usize>,
+    max_height: Option<usize>,
 }
 
 #[derive(PartialEq, Eq, Hash, Debug, Clone)]
@@ -33,12 +54,15 @@ enum Decorator {
     Blink,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-enum Alignment {
+/// Text alignment, START / CENTER / END stand for
+/// left / center / right horizontally and top / middle / bottom vertically
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Alignment {
     Start,
     Center,
     End,
 }
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum Layout {
     MarginTop,
@@ -51,8 +75,8 @@ enum Layout {
     PaddingRight,
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[allow(clippy::enum_variant_names)]
 enum Border {
-    //BorderStyle,
     BorderTop,
     BorderRight,
     BorderBottom,
@@ -61,285 +85,407 @@ enum Border {
 
 /// Create a new Style object
 ///
-/// With default width 80 and height 24
+/// Width and height follow the content, until set
 impl StyleBlock {
     pub fn new() -> StyleBlock {
         return StyleBlock {
-            style: Style {
-                decorator_dict: HashSet::new(),
-                decorated_space: true,
-                background_color: None,
-                foreground_color: None,
-            },
-            width: 80,
-            height: 24,
+            style: Style::new(),
+            width: None,
+            height: None,
+            vertical_alignment: None,
+            height_ellipsis: None,
             content: Vec::new(),
             raw_string: String::new(),
-            horizontal_alignment: Alignment::Start,
+            horizontal_alignment: None,
             layout_dict: HashMap::new(),
-            border_dict: HashSet::new(),
+            border_dict: HashMap::new(),
+            border_style: None,
+            border_decoration_dict: HashMap::new(),
             paragraph_fixed: false,
+            margin_background: None,
+            color_whitespace: None,
+            max_width: None,
+            max_height: None,
         };
     }
     /// Put Target Block to the Right of self
-    pub fn join_right(mut self, mut target: StyleBlock) -> StyleBlock {
-        self.render_paragraph();
-        target.render_paragraph();
-        let new_height = if self.height > target.height {
-            self.height
-        } else {
-            target.height
-        };
-        for i in 0..new_height {
-            if i >= self.height {
-                let padding_string = " ".repeat(self.width);
-                self.content.push(padding_string);
-            }
-            if i >= target.height {
-                let padding_string = " ".repeat(target.width);
-                target.content.push(padding_string);
-            }
-        }
-        for i in 0..new_height {
-            self.content[i].push_str(target.content[i].as_str());
-        }
-        self.width += target.width;
-        self.height = new_height;
-        return self;
+    ///
+    /// Both blocks are aligned to the top, see `join_horizontal` for other positions.
+    /// Each block keeps its own padding, border and margin,
+    /// the frame of `self` is no longer applied around the joined result
+    pub fn join_right(self, target: StyleBlock) -> StyleBlock {
+        return join_horizontal(Position::TOP, &[self, target]);
     }
     /// Put Target Block to the bottom of self
-    pub fn join_bottom(mut self, mut target: StyleBlock) -> StyleBlock {
-        self.render_paragraph();
-        target.render_paragraph();
-        if self.width > target.width {
-            let width_difference = self.width - target.width;
-            let padding_string = " ".repeat(width_difference);
-            for i in 0..target.height {
-                target.content[i].push_str(padding_string.clone().as_str());
-            }
-        } else {
-            let width_difference = target.width - self.width;
-            let padding_string = " ".repeat(width_difference);
-            for i in 0..target.height {
-                target.content[i].push_str(padding_string.clone().as_str());
-            }
-            self.width = target.width;
-        };
-
-        self.content.append(&mut target.content);
-        self.height += target.height;
-        return self;
-    }
-    pub fn finalize(mut self) -> String {
-        self.render_paragraph();
-        if self.layout_dict.contains_key(&Layout::PaddingTop) {
-            let size = self.layout_dict.get(&Layout::PaddingTop).unwrap();
-            self.pad_top(&size.clone());
-        }
-        if self.layout_dict.contains_key(&Layout::PaddingBottom) {
-            let size = self.layout_dict.get(&Layout::PaddingBottom).unwrap();
-            self.pad_bottom(&size.clone());
-        }
-        self.draw_border();
-        self.draw_margin();
-
+    ///
+    /// Both blocks are aligned to the left, see `join_vertical` for other positions.
+    /// Like `join_right`, each block keeps its own frame
+    pub fn join_bottom(self, target: StyleBlock) -> StyleBlock {
+        return join_vertical(Position::LEFT, &[self, target]);
+    }
+    /// Create a block from already rendered lines
+    ///
+    /// The lines are used as is, without wrapping or alignment
+    pub(crate) fn from_lines(lines: Vec<String>) -> StyleBlock {
+        let mut block = StyleBlock::new();
+        block.width = Some(
+            lines
+                .iter()
+                .map(|line| display_width(line))
+                .max()
+                .unwrap_or(0),
+        );
+        block.content = lines;
+        block.paragraph_fixed = true;
+        return block;
+    }
+    pub fn finalize(self) -> String {
         // Finalize output to String
         let mut content = String::new();
-        for i in self.content {
+        for i in self.render_lines() {
             content.push_str(i.as_str());
             content.push('\n');
         }
         return content;
     }
+    /// Render the whole block, border and margin included, without consuming it
+    ///
+    /// Rendering works on a copy and has no side effect. With the `tracing` feature,
+    /// layout decisions are reported as `trace` level events
+    pub(crate) fn render_lines(&self) -> Vec<String> {
+        let mut block = self.clone();
+        block.render_paragraph();
+        block.align_vertical();
+        let background = self.whitespace_background();
+        let (padding_top, padding_bottom) = self.vertical_padding_rows();
+        block.pad_top(&padding_top, &background);
+        block.pad_bottom(&padding_bottom, &background);
+        block.draw_border();
+        block.draw_margin();
+        block.clip();
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            width = block.content_width(),
+            height = block.content.len(),
+            "render block"
+        );
+        return block.content;
+    }
+    /// Clip the rendered block to the maximum width and height, border and margin included
+    fn clip(&mut self) {
+        if let Some(size) = self.max_height {
+            self.content.truncate(size);
+        }
+        if let Some(size) = self.max_width {
+            for i in self.content.iter_mut() {
+                *i = truncate(i, size, "");
+            }
+        }
+    }
+    /// Pad or clip the content to the fixed height, following the vertical alignment
+    ///
+    /// The fixed height includes top and bottom padding
+    fn align_vertical(&mut self) {
+        let size = match self.height {
+            Some(size) => size,
+            None => return,
+        };
+        let (padding_top, padding_bottom) = self.vertical_padding_rows();
+        let content_height = size - padding_top - padding_bottom;
+        if self.content.len() > content_height {
+            self.content.truncate(content_height);
+            if let (Some(ellipsis), false) = (&self.height_ellipsis, self.content.is_empty()) {
+                // Keep the ellipsis within the width set by the paragraph
+                let inner_width = self
+                    .width
+                    .unwrap_or(0)
+                    .saturating_sub(self.get_horizontal_padding());
+                let ellipsis = truncate(ellipsis, inner_width, "");
+                let decorated_line = self.style.line_text_decoration(ellipsis.clone());
+                let ellipsis_line = self.line_layout(decorated_line, display_width(&ellipsis));
+                self.content.pop();
+                self.content.push(ellipsis_line);
+            }
+            return;
+        }
+        let gap = content_height - self.content.len();
+        let (top, bottom) = match self.vertical_alignment.unwrap_or(Alignment::Start) {
+            Alignment::Start => (0, gap),
+            Alignment::Center => (gap / 2, gap - gap / 2),
+            Alignment::End => (gap, 0),
+        };
+        let background = self.whitespace_background();
+        self.pad_top(&top, &background);
+        self.pad_bottom(&bottom, &background);
+    }
+    /// Top and bottom padding rows, clipped so they fit in the fixed height
+    fn vertical_padding_rows(&self) -> (usize, usize) {
+        let top = self.get_padding_top();
+        let bottom = self.get_padding_bottom();
+        return match self.height {
+            Some(size) => {
+                let top = top.min(size);
+                (top, bottom.min(size - top))
+            }
+            None => (top, bottom),
+        };
+    }
+    /// Display width of the widest content line, at least the block width
+    fn content_width(&self) -> usize {
+        return self
+            .content
+            .iter()
+            .map(|line| display_width(line))
+            .max()
+            .unwrap_or(0)
+            .max(self.width.unwrap_or(0));
+    }
     fn render_paragraph(&mut self) {
         if self.paragraph_fixed {
             return;
         }
         self.content = self.text_wrap();
+        if self.width.is_none() {
+            // Size to the widest line, padding included
+            let text_width = self
+                .content
+                .iter()
+                .map(|line| display_width(line))
+                .max()
+                .unwrap_or(0);
+            self.width = Some(text_width + self.get_horizontal_padding());
+        }
         for i in 0..self.content.len() {
-            let text_length = dbg!(self.content[i].chars().count());
+            let text_length = display_width(&self.content[i]);
+            #[cfg(feature = "tracing")]
+            tracing::trace!(
+                line = i,
+                text_length,
+                width = ?self.width,
+                "layout paragraph line"
+            );
             let decorated_line = self.style.line_text_decoration(self.content[i].clone());
             self.content[i] = self.line_layout(decorated_line, text_length);
         }
-        self.height = self.content.len();
         self.paragraph_fixed = true;
     }
+    /// Surround the block with margins, painted with the margin background
     fn draw_margin(&mut self) {
-        match self.layout_dict.get(&Layout::MarginLeft) {
-            Some(size) => {
-                for i in self.content.iter_mut() {
-                    *i = Self::pad_left(i.clone(), size);
-                }
+        let background = self.margin_background.clone();
+        if let Some(size) = self.layout_dict.get(&Layout::MarginLeft) {
+            let margin = self.blank(*size, &background);
+            for i in self.content.iter_mut() {
+                *i = Self::pad_left(i.clone(), &margin);
             }
-            None => (),
-        };
-        match self.layout_dict.get(&Layout::MarginRight) {
-            Some(size) => {
-                for i in self.content.iter_mut() {
-                    *i = Self::pad_right(i.clone(), size);
-                }
-            }
-            None => (),
-        };
-        match self.layout_dict.get(&Layout::MarginTop) {
-            Some(size) => {
-                self.pad_top(&size.clone());
-            }
-            None => (),
-        };
-        match self.layout_dict.get(&Layout::MarginBottom) {
-            Some(size) => {
-                self.pad_bottom(&size.clone());
+        }
+        if let Some(size) = self.layout_dict.get(&Layout::MarginRight) {
+            let margin = self.blank(*size, &background);
+            for i in self.content.iter_mut() {
+                *i = Self::pad_right(i.clone(), &margin);
             }
-            None => (),
-        };
+        }
+        if let Some(size) = self.layout_dict.get(&Layout::MarginTop) {
+            self.pad_top(&size.clone(), &background);
+        }
+        if let Some(size) = self.layout_dict.get(&Layout::MarginBottom) {
+            self.pad_bottom(&size.clone(), &background);
+        }
     }
+    /// Draw the enabled border sides around the content
+    ///
+    /// Content lines are padded to the same width first,
+    /// and corners only take room when the side next to them is drawn,
+    /// so the block stays rectangular for any combination of sides
     fn draw_border(&mut self) {
-        let top_left = self.border_dict.contains(&Border::BorderLeft)
-            && self.border_dict.contains(&Border::BorderTop);
-        let top_right = self.border_dict.contains(&Border::BorderRight)
-            && self.border_dict.contains(&Border::BorderTop);
-        let bottom_left = self.border_dict.contains(&Border::BorderLeft)
-            && self.border_dict.contains(&Border::BorderBottom);
-        let bottom_right = self.border_dict.contains(&Border::BorderRight)
-            && self.border_dict.contains(&Border::BorderBottom);
-        for i in self.content.iter_mut() {
-            if self.border_dict.contains(&Border::BorderLeft) {
-                i.insert(0, '│');
+        let glyphs = &self.border_style.clone().unwrap_or_default();
+        let has_top = self.has_border(&Border::BorderTop);
+        let has_right = self.has_border(&Border::BorderRight);
+        let has_bottom = self.has_border(&Border::BorderBottom);
+        let has_left = self.has_border(&Border::BorderLeft);
+        let inner_width = self.content_width();
+        let left_width = if has_left {
+            edge_width(&glyphs.left)
+        } else {
+            0
+        };
+        let right_width = if has_right {
+            edge_width(&glyphs.right)
+        } else {
+            0
+        };
+        let background = self.whitespace_background();
+        let mut content = std::mem::take(&mut self.content);
+        for (row, i) in content.iter_mut().enumerate() {
+            let fill = inner_width - display_width(i);
+            i.push_str(&self.blank(fill, &background));
+            if has_left {
+                let edge = fit_glyph(&vertical_edge(&glyphs.left, row), " ", left_width);
+                i.insert_str(0, &self.paint_border(&Border::BorderLeft, edge));
             }
-            if self.border_dict.contains(&Border::BorderRight) {
-                i.push('│');
+            if has_right {
+                let edge = fit_glyph(&vertical_edge(&glyphs.right, row), " ", right_width);
+                i.push_str(&self.paint_border(&Border::BorderRight, edge));
             }
         }
-        if self.border_dict.contains(&Border::BorderTop) {
-            let mut borderline = "─".repeat(self.width);
-            if top_left {
-                borderline.insert(0, '┌');
-            }
-            if top_right {
-                borderline.push('┐');
-            }
-            self.content.insert(0, borderline);
+        let widths = (left_width, inner_width, right_width);
+        if has_top {
+            let borderline =
+                horizontal_border(&glyphs.top_left, &glyphs.top, &glyphs.top_right, widths);
+            content.insert(0, self.paint_border(&Border::BorderTop, borderline));
         }
-        if self.border_dict.contains(&Border::BorderBottom) {
-            let mut borderline = "─".repeat(self.width);
-            if bottom_left {
-                borderline.insert(0, '└');
-            }
-            if bottom_right {
-                borderline.push('┘');
-            }
-            self.content.push(borderline);
+        if has_bottom {
+            let borderline = horizontal_border(
+                &glyphs.bottom_left,
+                &glyphs.bottom,
+                &glyphs.bottom_right,
+                widths,
+            );
+            content.push(self.paint_border(&Border::BorderBottom, borderline));
         }
+        self.content = content;
+    }
+    /// Whether a border side is set and enabled
+    fn has_border(&self, side: &Border) -> bool {
+        return self.border_dict.get(side) == Some(&true);
+    }
+    /// Apply the colors and decorators of a border side
+    ///
+    /// Corners are painted with the top and bottom sides
+    fn paint_border(&self, side: &Border, edge: String) -> String {
+        return match self.border_decoration_dict.get(side) {
+            Some(style) => style.decorate_with(edge, &self.style.get_renderer()),
+            None => edge,
+        };
     }
     /// Apply text wrap
-    /// Would panic if valid text length is negative
+    ///
+    /// Text wraps within the width, or within the max width once the frame is taken out.
+    /// Without either, only line breaks in the text start new lines.
+    /// At least one column is left for the text, even when padding takes the whole width
     fn text_wrap(&self) -> Vec<String> {
-        let left_pad = match self.layout_dict.get(&Layout::PaddingLeft) {
-            Some(pad) => pad.to_owned(),
-            None => 0 as usize,
-        };
-        let right_pad = match self.layout_dict.get(&Layout::PaddingRight) {
-            Some(pad) => pad.to_owned(),
-            None => 0 as usize,
-        };
-        assert!(self.width > left_pad + right_pad);
-        let wrap_length = (self.width - left_pad - right_pad) as usize;
-        let mut new_wrapped_strings: Vec<String> = Vec::new();
-        for i in self.raw_string.split("\n") {
-            let mut to_wrap = i.to_owned();
-            while to_wrap.chars().count() > wrap_length {
-                let next = to_wrap.split_off(wrap_length);
-                new_wrapped_strings.push(to_wrap.clone());
-                to_wrap = next;
+        let text = expand_tabs(&self.raw_string);
+        let padding = self.get_horizontal_padding();
+        let wrap_length = match (self.width, self.max_width) {
+            (Some(width), _) => width.saturating_sub(padding).max(1),
+            (None, Some(max_width)) => {
+                let frame = self.get_horizontal_frame_size();
+                max_width.saturating_sub(frame).max(1)
             }
-            new_wrapped_strings.push(to_wrap);
-        }
-
-        return new_wrapped_strings;
+            (None, None) => {
+                return text
+                    .split('\n')
+                    .map(|line| line.strip_suffix('\r').unwrap_or(line).to_owned())
+                    .collect();
+            }
+        };
+        return wrap::wrap(&text, wrap_length);
     }
     fn line_layout(&self, mut raw_content: String, text_length: usize) -> String {
-        // Add Padding
-        if self.layout_dict.contains_key(&Layout::PaddingLeft) {
-            raw_content = Self::pad_left(
-                raw_content,
-                self.layout_dict.get(&Layout::PaddingLeft).unwrap(),
-            );
+        // Add Padding, filled with the block background
+        let background = &self.whitespace_background();
+        if let Some(size) = self.layout_dict.get(&Layout::PaddingLeft) {
+            raw_content = Self::pad_left(raw_content, &self.blank(*size, background));
         }
-        if self.layout_dict.contains_key(&Layout::PaddingRight) {
-            raw_content = Self::pad_right(
-                raw_content,
-                self.layout_dict.get(&Layout::PaddingRight).unwrap(),
-            );
+        if let Some(size) = self.layout_dict.get(&Layout::PaddingRight) {
+            raw_content = Self::pad_right(raw_content, &self.blank(*size, background));
         }
         let line_length = text_length
             + match self.layout_dict.get(&Layout::PaddingLeft) {
-                Some(i) => *i as usize,
-                None => 0 as usize,
+                Some(i) => *i,
+                None => 0,
             }
             + match self.layout_dict.get(&Layout::PaddingRight) {
-                Some(i) => *i as usize,
-                None => 0 as usize,
+                Some(i) => *i,
+                None => 0,
             };
-        raw_content =
-            self.align_horizontal(raw_content, &self.horizontal_alignment, dbg!(line_length));
+        raw_content = self.align_horizontal(
+            raw_content,
+            &self.horizontal_alignment.unwrap_or(Alignment::Start),
+            line_length,
+        );
 
         return raw_content;
     }
-    fn pad_top(&mut self, size: &usize) {
-        let pad = " ".repeat(self.width);
+    fn pad_top(&mut self, size: &usize, background: &Option<TerminalColor>) {
+        let pad = self.blank(self.content_width(), background);
         for _ in 0..*size {
             self.content.insert(0, pad.clone());
         }
     }
-    fn pad_bottom(&mut self, size: &usize) {
-        let pad = " ".repeat(self.width);
+    fn pad_bottom(&mut self, size: &usize, background: &Option<TerminalColor>) {
+        let pad = self.blank(self.content_width(), background);
         for _ in 0..*size {
             self.content.push(pad.clone())
         }
     }
-    fn pad_left(raw_content: String, size: &usize) -> String {
-        let mut padding_string = " ".repeat(*size as usize);
+    fn pad_left(raw_content: String, padding: &str) -> String {
+        let mut padding_string = padding.to_owned();
         padding_string.push_str(&raw_content);
         return padding_string;
     }
-    fn pad_right(mut raw_content: String, size: &usize) -> String {
-        let padding_string = " ".repeat(*size as usize);
-        raw_content.push_str(&padding_string);
+    fn pad_right(mut raw_content: String, padding: &str) -> String {
+        raw_content.push_str(padding);
         return raw_content;
     }
+    /// Background of the whitespace inside the block: padding, alignment filler and fill rows
+    ///
+    /// None when whitespace coloring is turned off with `set_color_whitespace`
+    fn whitespace_background(&self) -> Option<TerminalColor> {
+        if !self.color_whitespace.unwrap_or(true) {
+            return None;
+        }
+        return self.style.background_color.clone();
+    }
+    /// `width` spaces painted with `background`, plain spaces without a background
+    fn blank(&self, width: usize, background: &Option<TerminalColor>) -> String {
+        let spaces = " ".repeat(width);
+        return match background {
+            Some(color) if width > 0 => {
+                let fill = Style {
+                    background_color: Some(color.clone()),
+                    ..Style::new()
+                };
+                fill.decorate_with(spaces, &self.style.get_renderer())
+            }
+            _ => spaces,
+        };
+    }
     fn align_horizontal(
         &self,
         raw_content: String,
         alignment: &Alignment,
         text_length: usize,
     ) -> String {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(?alignment, text_length, width = ?self.width, "align line");
+        let block_width = self.width.unwrap_or(text_length);
+        let background = self.whitespace_background();
         let mut new_string = String::new();
         match *alignment {
             Alignment::Start => {
                 new_string.push_str(raw_content.as_str());
-                let pad = self.width - text_length;
+                let pad = block_width.saturating_sub(text_length);
                 if pad > 0 {
-                    let padding_string = " ".repeat(dbg!(pad));
+                    let padding_string = self.blank(pad, &background);
                     new_string.push_str(&padding_string);
                 }
             }
             Alignment::End => {
-                let pad = self.width - text_length;
+                let pad = block_width.saturating_sub(text_length);
                 if pad > 0 {
-                    let padding_string = " ".repeat(pad);
+                    let padding_string = self.blank(pad, &background);
                     new_string.push_str(&padding_string);
                 }
                 new_string.push_str(raw_content.as_str());
             }
             Alignment::Center => {
-                let double_pad = self.width - text_length;
+                let double_pad = block_width.saturating_sub(text_length);
                 let pad = double_pad / 2;
                 if double_pad > 0 {
-                    let padding_string = " ".repeat(pad);
+                    let padding_string = self.blank(pad, &background);
                     new_string.push_str(&padding_string);
                     new_string.push_str(raw_content.as_str());
-                    let padding_string = " ".repeat(double_pad - pad);
+                    let padding_string = self.blank(double_pad - pad, &background);
                     new_string.push_str(&padding_string);
                 } else {
                     new_string.push_str(raw_content.as_str());
@@ -350,13 +496,26 @@ impl StyleBlock {
     }
 }
 
+impl Default for StyleBlock {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
 impl Style {
     pub fn new() -> Style {
         return Style {
-            decorator_dict: HashSet::new(),
-            decorated_space: true,
+            decorator_dict: HashMap::new(),
+            decorated_space: None,
             background_color: None,
             foreground_color: None,
+            renderer: None,
         };
     }
     ///
@@ -370,13 +529,12 @@ impl Style {
     /// # Example
     ///
     /// ```
-    /// style.underline()
+    /// # use ripgross::Style::Style;
+    /// Style::new()
+    ///     .underline()
     ///     .strikethrough()
     ///     .blink()
-    ///     .padding_top(1)
-    ///     .padding_bottom(2)
-    ///     .render("Hello World")
-    ///
+    ///     .render("Hello World");
     /// ```
     pub fn render(self, raw_string: &str) -> String {
         let content = raw_string.to_string();
@@ -389,43 +547,76 @@ impl Style {
         return StyleBlock {
             raw_string: raw_string.to_owned(),
             style: self,
-            width: 80,
-            height: 24,
-            paragraph_fixed: false,
-            content: Vec::new(),
-            horizontal_alignment: Alignment::Start,
-            layout_dict: HashMap::new(),
-            border_dict: HashSet::new(),
+            ..StyleBlock::new()
         };
     }
     pub fn to_block(self) -> StyleBlock {
         return StyleBlock {
-            raw_string: String::new(),
             style: self,
-            width: 80,
-            height: 24,
-            paragraph_fixed: false,
-            content: Vec::new(),
-            horizontal_alignment: Alignment::Start,
-            layout_dict: HashMap::new(),
-            border_dict: HashSet::new(),
+            ..StyleBlock::new()
         };
     }
 
-    /// Apply Layout Decoration with following order:
-    /// Padding
-    /// Alignment
-    /// Border
-    /// Margin
+    /// Whether a decorator is set and enabled
+    fn has_decorator(&self, decorator: &Decorator) -> bool {
+        return self.decorator_dict.get(decorator) == Some(&true);
+    }
+    fn enabled_decorators(&self) -> impl Iterator<Item = &Decorator> {
+        return self
+            .decorator_dict
+            .iter()
+            .filter(|(_, enabled)| **enabled)
+            .map(|(decorator, _)| decorator);
+    }
+    /// Add the decorators of `other` and take its colors when they are set
+    fn overlay(&mut self, other: &Style) {
+        self.decorator_dict.extend(
+            other
+                .decorator_dict
+                .iter()
+                .map(|(decorator, value)| (decorator.clone(), *value)),
+        );
+        if other.foreground_color.is_some() {
+            self.foreground_color = other.foreground_color.clone();
+        }
+        if other.background_color.is_some() {
+            self.background_color = other.background_color.clone();
+        }
+    }
+
+    // Apply Layout Decoration with following order:
+    // Padding
+    // Alignment
+    // Border
+    // Margin
 
     /// Apply inline text decoration
     /// Mark decorated_space = true if you want space decorated
     fn line_text_decoration(&self, raw_content: String) -> String {
+        return self.decorate_with(raw_content, &self.get_renderer());
+    }
+    /// Apply inline text decoration for the output of `renderer`
+    ///
+    /// Colors are converted to the nearest ones supported by its color profile
+    fn decorate_with(&self, raw_content: String, renderer: &Renderer) -> String {
+        let profile = renderer.color_profile();
+        if profile == ColorProfile::Ascii {
+            return raw_content;
+        }
+        let dark_background = renderer.has_dark_background();
         let mut handler = nu_ansi_term::Style::new();
-        handler.background = self.background_color;
-        handler.foreground = self.foreground_color;
+        handler.background = self
+            .background_color
+            .as_ref()
+            .and_then(|color| profile.convert(color, dark_background))
+            .map(|color| color.to_ansi_term());
+        handler.foreground = self
+            .foreground_color
+            .as_ref()
+            .and_then(|color| profile.convert(color, dark_background))
+            .map(|color| color.to_ansi_term());
 
-        for x in self.decorator_dict.iter() {
+        for x in self.enabled_decorators() {
             match x {
                 Decorator::Bold => {
                     handler = handler.bold();
@@ -448,10 +639,10 @@ impl Style {
             }
         }
         let mut result = String::new();
-        if !self.decorated_space {
+        if !self.decorated_space.unwrap_or(true) {
             for word in raw_content.split(" ") {
                 result.push_str(handler.paint(word).to_string().as_str());
-                result.push_str(" ")
+                result.push(' ')
             }
             result.pop();
         } else {
@@ -460,5 +651,191 @@ impl Style {
         return result;
     }
 }
+mod border;
+mod color;
 pub mod getter;
+mod join;
+mod measure;
+mod place;
+mod position;
+mod profile;
+mod renderer;
 pub mod setter;
+pub mod unsetter;
+mod wrap;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_rectangular(block: StyleBlock, outer_width: usize, outer_height: usize) {
+        assert_eq!(block.get_width(), outer_width);
+        assert_eq!(block.get_height(), outer_height);
+        let rendered = block.finalize();
+        assert_eq!(rendered.lines().count(), outer_height);
+        for line in rendered.lines() {
+            assert_eq!(display_width(line), outer_width, "{:?}", line);
+        }
+    }
+
+    #[test]
+    fn border_keeps_block_rectangular() {
+        let block = || {
+            Style::new()
+                .render_to_block("你好世界")
+                .set_width(6)
+                .padding(&[1, 1])
+                .margin(&[1, 2])
+        };
+        assert_rectangular(block().border_top(), 10, 7);
+        assert_rectangular(block().border_sides(&[false, true]), 12, 6);
+        assert_rectangular(block().border_sides(&[true]), 12, 8);
+        assert_rectangular(block().border(BorderStyle::thick(), &[]), 12, 8);
+    }
+
+    #[test]
+    fn style_border_sides() {
+        let renderer = Renderer::new(std::io::sink());
+        renderer.set_color_profile(ColorProfile::TrueColor);
+        let block = Style::new()
+            .render_to_block("ab")
+            .renderer(&renderer)
+            .border(BorderStyle::normal(), &[])
+            .border_top_foreground_rgb(200, 0, 0)
+            .border_left_background_rgb(0, 0, 200)
+            .border_left_decoration(&Style::new().bold());
+        let expected = [
+            "\u{1b}[38;2;200;0;0m┌──┐\u{1b}[0m",
+            "\u{1b}[1;48;2;0;0;200m│\u{1b}[0mab│",
+            "└──┘",
+        ];
+        assert_eq!(block.finalize().lines().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn fixed_height_includes_padding() {
+        let block = || {
+            Style::new()
+                .render_to_block("a")
+                .set_width(3)
+                .set_height(5)
+                .padding(&[1, 0])
+        };
+        let text_row = |block: StyleBlock| block.finalize().lines().position(|line| line == "a  ");
+        assert_eq!(text_row(block().align_top()), Some(1));
+        assert_eq!(text_row(block().align_middle()), Some(2));
+        assert_eq!(text_row(block().align_bottom()), Some(3));
+        assert_eq!(block().get_height(), 5);
+
+        let clipped = Style::new()
+            .render_to_block("one\ntwo\nthree")
+            .set_width(5)
+            .set_height(4)
+            .padding(&[1, 0])
+            .height_ellipsis("…");
+        assert_eq!(clipped.finalize(), "     \none  \n…    \n     \n");
+
+        let long_ellipsis = Style::new()
+            .render_to_block("a\nb\nc")
+            .set_width(2)
+            .set_height(2)
+            .height_ellipsis("......");
+        assert_eq!(long_ellipsis.finalize(), "a \n..\n");
+
+        let padding_only = StyleBlock::new().padding(&[1, 0]);
+        assert_eq!(padding_only.clone().set_height(0).get_height(), 0);
+        assert_eq!(padding_only.set_height(1).get_height(), 1);
+    }
+
+    #[test]
+    fn paint_whitespace_with_backgrounds() {
+        let renderer = Renderer::new(std::io::sink());
+        renderer.set_color_profile(ColorProfile::TrueColor);
+        let block = Style::new()
+            .render_to_block("a")
+            .renderer(&renderer)
+            .background_rgb(0, 0, 200)
+            .set_width(5)
+            .set_height(4)
+            .padding(&[1, 1, 0])
+            .margin(&[0, 2])
+            .margin_background_rgb(200, 0, 0);
+        let paint = |color: &str, text: &str| format!("\u{1b}[48;2;{}m{}\u{1b}[0m", color, text);
+        let margin = paint("200;0;0", "  ");
+        let blank_row = format!("{}{}{}", margin, paint("0;0;200", "     "), margin);
+        let text_row = format!(
+            "{}{}{}{}{}{}",
+            margin,
+            paint("0;0;200", " "),
+            paint("0;0;200", "a"),
+            paint("0;0;200", " "),
+            paint("0;0;200", "  "),
+            margin
+        );
+        let expected = [&blank_row, &text_row, &blank_row, &blank_row];
+        assert_eq!(
+            block.clone().finalize().lines().collect::<Vec<_>>(),
+            expected
+        );
+
+        let plain_row = format!("{}     {}", margin, margin);
+        let text_row = format!("{} {}   {}", margin, paint("0;0;200", "a"), margin);
+        let expected = [&plain_row, &text_row, &plain_row, &plain_row];
+        let unpainted = block.set_color_whitespace(false).finalize();
+        assert_eq!(unpainted.lines().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn clip_styled_block_to_max_size() {
+        let renderer = Renderer::new(std::io::sink());
+        renderer.set_color_profile(ColorProfile::TrueColor);
+        let block = Style::new()
+            .bold()
+            .foreground_rgb(0, 200, 0)
+            .render_to_block("hello world")
+            .renderer(&renderer)
+            .set_width(11)
+            .border(BorderStyle::normal(), &[])
+            .margin(&[1, 2])
+            .max_width(7)
+            .max_height(3);
+        assert_eq!(block.get_width(), 7);
+        assert_eq!(block.get_height(), 3);
+        let expected = [
+            "       ",
+            "  ┌────",
+            "  │\u{1b}[1;38;2;0;200;0mhell\u{1b}[0m",
+        ];
+        assert_eq!(block.finalize().lines().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn size_to_content_without_width() {
+        let block = Style::new()
+            .render_to_block("ab\ncdef")
+            .padding(&[0, 1])
+            .border(BorderStyle::normal(), &[]);
+        assert_eq!(block.get_block_width(), None);
+        let expected = ["┌──────┐", "│ ab   │", "│ cdef │", "└──────┘"];
+        assert_eq!(block.finalize().lines().collect::<Vec<_>>(), expected);
+
+        let crlf = Style::new()
+            .render_to_block("ab\r\ncd")
+            .border_sides(&[true]);
+        assert_eq!(crlf.finalize(), "┌──┐\n│ab│\n│cd│\n└──┘\n");
+
+        let wrapped = Style::new()
+            .render_to_block("one two three")
+            .border(BorderStyle::normal(), &[])
+            .max_width(9);
+        let expected = ["┌───────┐", "│one two│", "│three  │", "└───────┘"];
+        assert_eq!(wrapped.finalize().lines().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn getters_do_not_panic_on_narrow_width() {
+        let block = StyleBlock::new().set_width(2).padding(&[0, 1]);
+        assert_eq!(block.get_width(), 2);
+        assert_eq!(block.get_height(), 1);
+    }
+}
@@ -0,0 +1,192 @@
+use super::measure::{display_width, grapheme_width, truncate};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Glyphs used to draw the border of a StyleBlock
+///
+/// Use one of the presets, or fill in the fields for a custom border.
+/// The middle joints are only used by components drawing inner separators, like tables
+///
+/// # Example
+///
+/// ```
+/// # use ripgross::Style::*;
+/// let dashed = BorderStyle {
+///     top: "╌".to_owned(),
+///     bottom: "╌".to_owned(),
+///     ..BorderStyle::rounded()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BorderStyle {
+    pub top: String,
+    pub bottom: String,
+    pub left: String,
+    pub right: String,
+    pub top_left: String,
+    pub top_right: String,
+    pub bottom_left: String,
+    pub bottom_right: String,
+    pub middle_left: String,
+    pub middle_right: String,
+    pub middle: String,
+    pub middle_top: String,
+    pub middle_bottom: String,
+}
+
+impl BorderStyle {
+    #[allow(clippy::too_many_arguments)]
+    fn from_glyphs(
+        top: &str,
+        bottom: &str,
+        left: &str,
+        right: &str,
+        top_left: &str,
+        top_right: &str,
+        bottom_left: &str,
+        bottom_right: &str,
+        middle_left: &str,
+        middle_right: &str,
+        middle: &str,
+        middle_top: &str,
+        middle_bottom: &str,
+    ) -> BorderStyle {
+        return BorderStyle {
+            top: top.to_owned(),
+            bottom: bottom.to_owned(),
+            left: left.to_owned(),
+            right: right.to_owned(),
+            top_left: top_left.to_owned(),
+            top_right: top_right.to_owned(),
+            bottom_left: bottom_left.to_owned(),
+            bottom_right: bottom_right.to_owned(),
+            middle_left: middle_left.to_owned(),
+            middle_right: middle_right.to_owned(),
+            middle: middle.to_owned(),
+            middle_top: middle_top.to_owned(),
+            middle_bottom: middle_bottom.to_owned(),
+        };
+    }
+    /// ┌──┐ Square corners with thin lines
+    pub fn normal() -> BorderStyle {
+        return Self::from_glyphs(
+            "─", "─", "│", "│", "┌", "┐", "└", "┘", "├", "┤", "┼", "┬", "┴",
+        );
+    }
+    /// ╭──╮ Rounded corners with thin lines
+    pub fn rounded() -> BorderStyle {
+        return Self::from_glyphs(
+            "─", "─", "│", "│", "╭", "╮", "╰", "╯", "├", "┤", "┼", "┬", "┴",
+        );
+    }
+    /// ┏━━┓ Square corners with thick lines
+    pub fn thick() -> BorderStyle {
+        return Self::from_glyphs(
+            "━", "━", "┃", "┃", "┏", "┓", "┗", "┛", "┣", "┫", "╋", "┳", "┻",
+        );
+    }
+    /// ╔══╗ Square corners with double lines
+    pub fn double() -> BorderStyle {
+        return Self::from_glyphs(
+            "═", "═", "║", "║", "╔", "╗", "╚", "╝", "╠", "╣", "╬", "╦", "╩",
+        );
+    }
+    /// ████ Solid full blocks
+    pub fn block() -> BorderStyle {
+        return Self::from_glyphs(
+            "█", "█", "█", "█", "█", "█", "█", "█", "█", "█", "█", "█", "█",
+        );
+    }
+    /// ▛▀▀▜ Half blocks drawn on the outside of the cell
+    pub fn outer_half_block() -> BorderStyle {
+        return Self::from_glyphs("▀", "▄", "▌", "▐", "▛", "▜", "▙", "▟", "", "", "", "", "");
+    }
+    /// ▗▄▄▖ Half blocks drawn on the inside of the cell
+    pub fn inner_half_block() -> BorderStyle {
+        return Self::from_glyphs("▄", "▀", "▐", "▌", "▗", "▖", "▝", "▘", "", "", "", "", "");
+    }
+    /// Border made of spaces, takes the room of a border without showing it
+    pub fn hidden() -> BorderStyle {
+        return Self::from_glyphs(
+            " ", " ", " ", " ", " ", " ", " ", " ", " ", " ", " ", " ", " ",
+        );
+    }
+    /// +--+ Plain ASCII characters, for terminals without box drawing glyphs
+    pub fn ascii() -> BorderStyle {
+        return Self::from_glyphs(
+            "-", "-", "|", "|", "+", "+", "+", "+", "+", "+", "+", "+", "+",
+        );
+    }
+}
+
+impl Default for BorderStyle {
+    fn default() -> Self {
+        return Self::normal();
+    }
+}
+
+/// Repeat the glyphs of `pattern` until the edge is `width` columns wide
+///
+/// An empty pattern is drawn as spaces
+pub(crate) fn horizontal_edge(pattern: &str, width: usize) -> String {
+    let glyphs: Vec<&str> = pattern.graphemes(true).collect();
+    if glyphs.is_empty() {
+        return " ".repeat(width);
+    }
+    let mut edge = String::new();
+    let mut edge_width = 0;
+    for glyph in glyphs.iter().cycle() {
+        let glyph_width = grapheme_width(glyph).max(1);
+        if edge_width + glyph_width > width {
+            break;
+        }
+        edge.push_str(glyph);
+        edge_width += glyph_width;
+    }
+    edge.push_str(&" ".repeat(width - edge_width));
+    return edge;
+}
+
+/// Glyph of a vertical edge at `row`
+///
+/// Multi glyph edges are cycled through row by row, an empty edge is drawn as a space
+pub(crate) fn vertical_edge(pattern: &str, row: usize) -> String {
+    let glyphs: Vec<&str> = pattern.graphemes(true).collect();
+    if glyphs.is_empty() {
+        return " ".to_owned();
+    }
+    return glyphs[row % glyphs.len()].to_owned();
+}
+
+/// Display width of a border side, at least one column
+pub(crate) fn edge_width(pattern: &str) -> usize {
+    return pattern
+        .graphemes(true)
+        .map(grapheme_width)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+}
+
+/// Fit a glyph into exactly `width` columns, the rest is filled with the glyphs of `filler`
+pub(crate) fn fit_glyph(glyph: &str, filler: &str, width: usize) -> String {
+    let mut fitted = truncate(glyph, width, "");
+    let rest = width - display_width(&fitted);
+    fitted.push_str(&horizontal_edge(filler, rest));
+    return fitted;
+}
+
+/// Top or bottom border line: corners fitted into the width of the side borders around the edge
+///
+/// A corner is left out when its side border has no width
+pub(crate) fn horizontal_border(
+    left_corner: &str,
+    edge: &str,
+    right_corner: &str,
+    widths: (usize, usize, usize),
+) -> String {
+    let (left_width, inner_width, right_width) = widths;
+    let mut borderline = fit_glyph(left_corner, edge, left_width);
+    borderline.push_str(&horizontal_edge(edge, inner_width));
+    borderline.push_str(&fit_glyph(right_corner, edge, right_width));
+    return borderline;
+}
@@ -0,0 +1,422 @@
+use nu_ansi_term::Color;
+use std::fmt;
+use std::str::FromStr;
+
+/// A color for text, background, border or whitespace
+///
+/// Colors could be built directly, or parsed from a string:
+/// `#rrggbb` and `#rgb` hex codes, a palette index from `0` to `255`,
+/// or a CSS color name like `coral` or `rebeccapurple`
+///
+/// # Example
+///
+/// ```
+/// # use ripgross::Style::*;
+/// let accent: TerminalColor = "#ff8700".parse().unwrap();
+/// assert_eq!(accent, TerminalColor::Rgb(255, 135, 0));
+/// assert_eq!(TerminalColor::parse("9"), Ok(TerminalColor::Ansi(9)));
+/// assert!(TerminalColor::parse("#12345").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TerminalColor {
+    /// One of the 16 basic ANSI colors, 0-7 normal and 8-15 bright
+    ///
+    /// Indices above 15 are handled as `Ansi256`
+    Ansi(u8),
+    /// Index in the 256 colors palette
+    Ansi256(u8),
+    /// 24-bit truecolor
+    Rgb(u8, u8, u8),
+    /// Color depending on the terminal background, see `AdaptiveColor`
+    Adaptive(Box<AdaptiveColor>),
+    /// Color given for every color profile, see `CompleteColor`
+    Complete(CompleteColor),
+}
+
+/// Color picked at render time depending on whether the terminal background is light or dark
+///
+/// # Example
+///
+/// ```
+/// # use ripgross::Style::*;
+/// let text = AdaptiveColor {
+///     light: TerminalColor::Rgb(30, 30, 30),
+///     dark: TerminalColor::Rgb(230, 230, 230),
+/// };
+/// Style::new().foreground(text).render("readable on both");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AdaptiveColor {
+    pub light: TerminalColor,
+    pub dark: TerminalColor,
+}
+
+/// Color given for every color profile, used as is instead of being converted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompleteColor {
+    pub true_color: (u8, u8, u8),
+    pub ansi256: u8,
+    pub ansi: u8,
+}
+
+/// `CompleteColor` picked depending on whether the terminal background is light or dark
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompleteAdaptiveColor {
+    pub light: CompleteColor,
+    pub dark: CompleteColor,
+}
+
+impl From<AdaptiveColor> for TerminalColor {
+    fn from(color: AdaptiveColor) -> Self {
+        return TerminalColor::Adaptive(Box::new(color));
+    }
+}
+
+impl From<CompleteColor> for TerminalColor {
+    fn from(color: CompleteColor) -> Self {
+        return TerminalColor::Complete(color);
+    }
+}
+
+impl From<CompleteAdaptiveColor> for TerminalColor {
+    fn from(color: CompleteAdaptiveColor) -> Self {
+        return TerminalColor::from(AdaptiveColor {
+            light: TerminalColor::Complete(color.light),
+            dark: TerminalColor::Complete(color.dark),
+        });
+    }
+}
+
+/// Error returned when a color can't be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColorError {
+    /// Not a `#rrggbb` or `#rgb` hex code
+    InvalidHex(String),
+    /// A number outside of the 256 colors palette
+    OutOfRange(String),
+    /// Neither a hex code, a palette index nor a known color name
+    UnknownName(String),
+}
+
+impl fmt::Display for ColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            ColorError::InvalidHex(value) => write!(f, "invalid hex color `{}`", value),
+            ColorError::OutOfRange(value) => {
+                write!(f, "color index `{}` is out of the 0-255 range", value)
+            }
+            ColorError::UnknownName(value) => write!(f, "unknown color name `{}`", value),
+        };
+    }
+}
+
+impl std::error::Error for ColorError {}
+
+impl TerminalColor {
+    /// Parse a hex code, a palette index or a color name
+    ///
+    /// Palette indices from 0 to 15 are basic ANSI colors, 16 to 255 are `Ansi256`
+    pub fn parse(value: &str) -> Result<TerminalColor, ColorError> {
+        let value = value.trim();
+        if value.starts_with('#') {
+            return Self::hex(value);
+        }
+        if !value.is_empty() && value.chars().all(|c| c.is_ascii_digit()) {
+            return match value.parse::<u8>() {
+                Ok(index) if index < 16 => Ok(TerminalColor::Ansi(index)),
+                Ok(index) => Ok(TerminalColor::Ansi256(index)),
+                Err(_) => Err(ColorError::OutOfRange(value.to_owned())),
+            };
+        }
+        return Self::named(value);
+    }
+    /// Parse a `#rrggbb` or `#rgb` hex code, the `#` is optional
+    pub fn hex(value: &str) -> Result<TerminalColor, ColorError> {
+        let digits = value.trim().trim_start_matches('#');
+        let invalid = || ColorError::InvalidHex(value.to_owned());
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+        let channel = |hex: &str| u8::from_str_radix(hex, 16).map_err(|_| invalid());
+        return match digits.len() {
+            6 => Ok(TerminalColor::Rgb(
+                channel(&digits[0..2])?,
+                channel(&digits[2..4])?,
+                channel(&digits[4..6])?,
+            )),
+            3 => Ok(TerminalColor::Rgb(
+                channel(&digits[0..1])? * 17,
+                channel(&digits[1..2])? * 17,
+                channel(&digits[2..3])? * 17,
+            )),
+            _ => Err(invalid()),
+        };
+    }
+    /// Look up a CSS color name, case and `-`, `_`, ` ` separators are ignored
+    pub fn named(value: &str) -> Result<TerminalColor, ColorError> {
+        let name: String = value
+            .chars()
+            .filter(|c| !matches!(c, '-' | '_' | ' '))
+            .collect::<String>()
+            .to_ascii_lowercase();
+        return match CSS_COLORS.binary_search_by(|(key, _)| key.cmp(&name.as_str())) {
+            Ok(index) => {
+                let rgb = CSS_COLORS[index].1;
+                Ok(TerminalColor::Rgb(
+                    (rgb >> 16) as u8,
+                    (rgb >> 8) as u8,
+                    rgb as u8,
+                ))
+            }
+            Err(_) => Err(ColorError::UnknownName(value.to_owned())),
+        };
+    }
+    /// Red, green and blue channels of the color
+    ///
+    /// Palette colors use the default xterm palette,
+    /// adaptive colors use their dark variant
+    pub fn to_rgb(&self) -> (u8, u8, u8) {
+        return match *self {
+            TerminalColor::Rgb(r, g, b) => (r, g, b),
+            TerminalColor::Adaptive(ref color) => color.dark.to_rgb(),
+            TerminalColor::Complete(color) => color.true_color,
+            TerminalColor::Ansi(index) | TerminalColor::Ansi256(index) if index < 16 => {
+                let rgb = ANSI_RGB[index as usize];
+                ((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8)
+            }
+            TerminalColor::Ansi(index) | TerminalColor::Ansi256(index) if index < 232 => {
+                let levels = [0, 95, 135, 175, 215, 255];
+                let cube = index - 16;
+                (
+                    levels[(cube / 36) as usize],
+                    levels[(cube / 6 % 6) as usize],
+                    levels[(cube % 6) as usize],
+                )
+            }
+            TerminalColor::Ansi(index) | TerminalColor::Ansi256(index) => {
+                let gray = 8 + 10 * (index - 232);
+                (gray, gray, gray)
+            }
+        };
+    }
+    /// Color as understood by `nu_ansi_term`
+    ///
+    /// Adaptive and complete colors should be resolved with `ColorProfile::convert` first
+    pub(crate) fn to_ansi_term(&self) -> Color {
+        return match *self {
+            TerminalColor::Ansi(index) if index < 16 => ANSI_COLORS[index as usize],
+            TerminalColor::Ansi(index) | TerminalColor::Ansi256(index) => Color::Fixed(index),
+            TerminalColor::Rgb(r, g, b) => Color::Rgb(r, g, b),
+            TerminalColor::Adaptive(ref color) => color.dark.to_ansi_term(),
+            TerminalColor::Complete(color) => {
+                let (r, g, b) = color.true_color;
+                Color::Rgb(r, g, b)
+            }
+        };
+    }
+}
+
+impl FromStr for TerminalColor {
+    type Err = ColorError;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        return TerminalColor::parse(value);
+    }
+}
+
+const ANSI_COLORS: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Purple,
+    Color::Cyan,
+    Color::White,
+    Color::DarkGray,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightPurple,
+    Color::LightCyan,
+    Color::LightGray,
+];
+
+/// Default xterm values of the 16 ANSI colors
+const ANSI_RGB: [u32; 16] = [
+    0x000000, 0x800000, 0x008000, 0x808000, 0x000080, 0x800080, 0x008080, 0xc0c0c0, 0x808080,
+    0xff0000, 0x00ff00, 0xffff00, 0x0000ff, 0xff00ff, 0x00ffff, 0xffffff,
+];
+
+/// CSS named colors, sorted by name for binary search
+const CSS_COLORS: [(&str, u32); 148] = [
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn css_colors_are_sorted() {
+        assert!(CSS_COLORS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert_eq!(
+            TerminalColor::parse("Rebecca-Purple"),
+            Ok(TerminalColor::Rgb(0x66, 0x33, 0x99))
+        );
+        assert_eq!(
+            TerminalColor::parse("#0af"),
+            Ok(TerminalColor::Rgb(0x00, 0xaa, 0xff))
+        );
+        assert_eq!(TerminalColor::parse("200"), Ok(TerminalColor::Ansi256(200)));
+        assert!(TerminalColor::parse("256").is_err());
+        assert!(TerminalColor::parse("notacolor").is_err());
+    }
+}
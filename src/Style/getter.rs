@@ -1,30 +1,282 @@
+use super::border::edge_width;
 use super::*;
 impl StyleBlock {
+    /// Width of the rendered block, border and margin included
     pub fn get_width(&self) -> usize {
-        return self.width;
+        return self
+            .render_lines()
+            .iter()
+            .map(|line| display_width(line))
+            .max()
+            .unwrap_or(0);
     }
+    /// Height of the rendered block, border and margin included
     pub fn get_height(&self) -> usize {
+        return self.render_lines().len();
+    }
+    /// Width set with `set_width`, padding included, border and margin excluded
+    pub fn get_block_width(&self) -> Option<usize> {
+        return self.width;
+    }
+    /// Height set with `set_height`, padding included, border and margin excluded
+    pub fn get_block_height(&self) -> Option<usize> {
         return self.height;
     }
+    pub fn get_max_width(&self) -> Option<usize> {
+        return self.max_width;
+    }
+    pub fn get_max_height(&self) -> Option<usize> {
+        return self.max_height;
+    }
+    /// Ellipsis marking clipped content, see `height_ellipsis`
+    pub fn get_height_ellipsis(&self) -> Option<String> {
+        return self.height_ellipsis.clone();
+    }
+    /// Inline style of the text
+    pub fn get_style(&self) -> Style {
+        return self.style.clone();
+    }
+    pub fn get_padding_top(&self) -> usize {
+        return self.layout_size(&Layout::PaddingTop);
+    }
+    pub fn get_padding_right(&self) -> usize {
+        return self.layout_size(&Layout::PaddingRight);
+    }
+    pub fn get_padding_bottom(&self) -> usize {
+        return self.layout_size(&Layout::PaddingBottom);
+    }
+    pub fn get_padding_left(&self) -> usize {
+        return self.layout_size(&Layout::PaddingLeft);
+    }
+    pub fn get_margin_top(&self) -> usize {
+        return self.layout_size(&Layout::MarginTop);
+    }
+    pub fn get_margin_right(&self) -> usize {
+        return self.layout_size(&Layout::MarginRight);
+    }
+    pub fn get_margin_bottom(&self) -> usize {
+        return self.layout_size(&Layout::MarginBottom);
+    }
+    pub fn get_margin_left(&self) -> usize {
+        return self.layout_size(&Layout::MarginLeft);
+    }
+    /// Whether whitespace is painted with the background, see `set_color_whitespace`
+    pub fn get_color_whitespace(&self) -> bool {
+        return self.color_whitespace.unwrap_or(true);
+    }
+    pub fn get_margin_background(&self) -> Option<TerminalColor> {
+        return self.margin_background.clone();
+    }
+    pub fn get_horizontal_alignment(&self) -> Alignment {
+        return self.horizontal_alignment.unwrap_or(Alignment::Start);
+    }
+    pub fn get_vertical_alignment(&self) -> Alignment {
+        return self.vertical_alignment.unwrap_or(Alignment::Start);
+    }
+    pub fn get_border_top(&self) -> bool {
+        return self.has_border(&Border::BorderTop);
+    }
+    pub fn get_border_right(&self) -> bool {
+        return self.has_border(&Border::BorderRight);
+    }
+    pub fn get_border_bottom(&self) -> bool {
+        return self.has_border(&Border::BorderBottom);
+    }
+    pub fn get_border_left(&self) -> bool {
+        return self.has_border(&Border::BorderLeft);
+    }
+    pub fn get_border_style(&self) -> BorderStyle {
+        return self.border_style.clone().unwrap_or_default();
+    }
+    pub fn get_border_top_foreground(&self) -> Option<TerminalColor> {
+        return self
+            .border_decoration_dict
+            .get(&Border::BorderTop)
+            .and_then(|style| style.foreground_color.clone());
+    }
+    pub fn get_border_top_background(&self) -> Option<TerminalColor> {
+        return self
+            .border_decoration_dict
+            .get(&Border::BorderTop)
+            .and_then(|style| style.background_color.clone());
+    }
+    pub fn get_border_right_foreground(&self) -> Option<TerminalColor> {
+        return self
+            .border_decoration_dict
+            .get(&Border::BorderRight)
+            .and_then(|style| style.foreground_color.clone());
+    }
+    pub fn get_border_right_background(&self) -> Option<TerminalColor> {
+        return self
+            .border_decoration_dict
+            .get(&Border::BorderRight)
+            .and_then(|style| style.background_color.clone());
+    }
+    pub fn get_border_bottom_foreground(&self) -> Option<TerminalColor> {
+        return self
+            .border_decoration_dict
+            .get(&Border::BorderBottom)
+            .and_then(|style| style.foreground_color.clone());
+    }
+    pub fn get_border_bottom_background(&self) -> Option<TerminalColor> {
+        return self
+            .border_decoration_dict
+            .get(&Border::BorderBottom)
+            .and_then(|style| style.background_color.clone());
+    }
+    pub fn get_border_left_foreground(&self) -> Option<TerminalColor> {
+        return self
+            .border_decoration_dict
+            .get(&Border::BorderLeft)
+            .and_then(|style| style.foreground_color.clone());
+    }
+    pub fn get_border_left_background(&self) -> Option<TerminalColor> {
+        return self
+            .border_decoration_dict
+            .get(&Border::BorderLeft)
+            .and_then(|style| style.background_color.clone());
+    }
+    pub fn get_foreground(&self) -> Option<TerminalColor> {
+        return self.style.get_foreground();
+    }
+    pub fn get_background(&self) -> Option<TerminalColor> {
+        return self.style.get_background();
+    }
+    pub fn get_decorated_space(&self) -> bool {
+        return self.style.get_decorated_space();
+    }
+    pub fn is_bold(&self) -> bool {
+        return self.style.is_bold();
+    }
+    pub fn is_italic(&self) -> bool {
+        return self.style.is_italic();
+    }
+    pub fn is_strikethrough(&self) -> bool {
+        return self.style.is_strikethrough();
+    }
+    pub fn is_underline(&self) -> bool {
+        return self.style.is_underline();
+    }
+    pub fn is_blink(&self) -> bool {
+        return self.style.is_blink();
+    }
+    pub fn is_reverse(&self) -> bool {
+        return self.style.is_reverse();
+    }
+    /// Left and right padding
+    pub fn get_horizontal_padding(&self) -> usize {
+        return self.get_padding_left() + self.get_padding_right();
+    }
+    /// Top and bottom padding
+    pub fn get_vertical_padding(&self) -> usize {
+        return self.get_padding_top() + self.get_padding_bottom();
+    }
+    /// Left and right margins
+    pub fn get_horizontal_margins(&self) -> usize {
+        return self.get_margin_left() + self.get_margin_right();
+    }
+    /// Top and bottom margins
+    pub fn get_vertical_margins(&self) -> usize {
+        return self.get_margin_top() + self.get_margin_bottom();
+    }
+    /// Width of the left and right borders
+    pub fn get_horizontal_border_size(&self) -> usize {
+        let glyphs = self.get_border_style();
+        let left = if self.get_border_left() {
+            edge_width(&glyphs.left)
+        } else {
+            0
+        };
+        let right = if self.get_border_right() {
+            edge_width(&glyphs.right)
+        } else {
+            0
+        };
+        return left + right;
+    }
+    /// Height of the top and bottom borders
+    pub fn get_vertical_border_size(&self) -> usize {
+        return self.get_border_top() as usize + self.get_border_bottom() as usize;
+    }
+    /// Columns taken around the text by padding, border and margin
+    ///
+    /// The width left for the text is the available width minus the frame size
+    pub fn get_horizontal_frame_size(&self) -> usize {
+        return self.get_horizontal_padding()
+            + self.get_horizontal_border_size()
+            + self.get_horizontal_margins();
+    }
+    /// Lines taken around the text by padding, border and margin
+    pub fn get_vertical_frame_size(&self) -> usize {
+        return self.get_vertical_padding()
+            + self.get_vertical_border_size()
+            + self.get_vertical_margins();
+    }
+    fn layout_size(&self, layout: &Layout) -> usize {
+        return *self.layout_dict.get(layout).unwrap_or(&0);
+    }
 }
 
 impl Style {
+    /// Renderer the style is bound to, the default renderer otherwise
+    pub fn get_renderer(&self) -> Renderer {
+        return match &self.renderer {
+            Some(renderer) => renderer.clone(),
+            None => default_renderer(),
+        };
+    }
+    pub fn get_foreground(&self) -> Option<TerminalColor> {
+        return self.foreground_color.clone();
+    }
+    pub fn get_background(&self) -> Option<TerminalColor> {
+        return self.background_color.clone();
+    }
+    /// Whether spaces are decorated, see `ignore_space`
+    pub fn get_decorated_space(&self) -> bool {
+        return self.decorated_space.unwrap_or(true);
+    }
     pub fn is_bold(&self) -> bool {
-        return self.decorator_dict.contains(&Decorator::Bold);
+        return self.has_decorator(&Decorator::Bold);
     }
     pub fn is_italic(&self) -> bool {
-        return self.decorator_dict.contains(&Decorator::Italic);
+        return self.has_decorator(&Decorator::Italic);
     }
     pub fn is_strikethrough(&self) -> bool {
-        return self.decorator_dict.contains(&Decorator::Strikethrough);
+        return self.has_decorator(&Decorator::Strikethrough);
     }
     pub fn is_underline(&self) -> bool {
-        return self.decorator_dict.contains(&Decorator::Underline);
+        return self.has_decorator(&Decorator::Underline);
     }
     pub fn is_blink(&self) -> bool {
-        return self.decorator_dict.contains(&Decorator::Blink);
+        return self.has_decorator(&Decorator::Blink);
     }
     pub fn is_reverse(&self) -> bool {
-        return self.decorator_dict.contains(&Decorator::Reverse);
+        return self.has_decorator(&Decorator::Reverse);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_sizes() {
+        let block = StyleBlock::new()
+            .padding(&[1, 2, 3, 4])
+            .margin(&[5, 6, 7, 8])
+            .border(BorderStyle::normal(), &[true, false]);
+        assert_eq!(block.get_horizontal_padding(), 6);
+        assert_eq!(block.get_vertical_padding(), 4);
+        assert_eq!(block.get_horizontal_margins(), 14);
+        assert_eq!(block.get_vertical_margins(), 12);
+        assert_eq!(block.get_horizontal_border_size(), 0);
+        assert_eq!(block.get_vertical_border_size(), 2);
+        assert_eq!(block.get_horizontal_frame_size(), 20);
+        assert_eq!(block.get_vertical_frame_size(), 18);
+
+        let sides = StyleBlock::new().border_sides(&[false, true]);
+        assert_eq!(sides.get_horizontal_border_size(), 2);
+        assert_eq!(sides.get_vertical_border_size(), 0);
+        assert_eq!(StyleBlock::new().get_horizontal_frame_size(), 0);
     }
 }
@@ -0,0 +1,113 @@
+use super::*;
+
+/// Put blocks side by side, from left to right
+///
+/// Every block is rendered with its own padding, border and margin first.
+/// Shorter blocks are placed along the vertical axis with `position`,
+/// e.g. `Position::TOP`, `Position::CENTER`, `Position::BOTTOM` or any fraction in between
+///
+/// # Example
+///
+/// ```
+/// # use ripgross::Style::*;
+/// let left = Style::new().render_to_block("one\ntwo\nthree").set_width(5);
+/// let right = Style::new().render_to_block("four").set_width(4);
+/// let joined = join_horizontal(Position::CENTER, &[left, right]);
+/// assert_eq!(joined.get_width(), 9);
+/// assert_eq!(joined.get_height(), 3);
+/// ```
+pub fn join_horizontal(position: Position, blocks: &[StyleBlock]) -> StyleBlock {
+    let rendered: Vec<Vec<String>> = blocks.iter().map(|block| block.render_lines()).collect();
+    let new_height = rendered.iter().map(|lines| lines.len()).max().unwrap_or(0);
+    let mut content: Vec<String> = vec![String::new(); new_height];
+    for lines in rendered {
+        let block_width = lines
+            .iter()
+            .map(|line| display_width(line))
+            .max()
+            .unwrap_or(0);
+        let (top, _) = position.split(new_height - lines.len());
+        let padding_string = " ".repeat(block_width);
+        for (i, row) in content.iter_mut().enumerate() {
+            match i.checked_sub(top).and_then(|i| lines.get(i)) {
+                Some(line) => {
+                    row.push_str(line);
+                    row.push_str(&" ".repeat(block_width - display_width(line)));
+                }
+                None => row.push_str(&padding_string),
+            }
+        }
+    }
+    return StyleBlock::from_lines(content);
+}
+
+/// Stack blocks on top of each other, from top to bottom
+///
+/// Every block is rendered with its own padding, border and margin first.
+/// Narrower blocks are placed along the horizontal axis with `position`,
+/// e.g. `Position::LEFT`, `Position::CENTER`, `Position::RIGHT` or any fraction in between
+pub fn join_vertical(position: Position, blocks: &[StyleBlock]) -> StyleBlock {
+    let rendered: Vec<Vec<String>> = blocks.iter().map(|block| block.render_lines()).collect();
+    let new_width = rendered
+        .iter()
+        .flatten()
+        .map(|line| display_width(line))
+        .max()
+        .unwrap_or(0);
+    let mut content: Vec<String> = Vec::new();
+    for line in rendered.into_iter().flatten() {
+        let (left, right) = position.split(new_width - display_width(&line));
+        let mut row = " ".repeat(left);
+        row.push_str(&line);
+        row.push_str(&" ".repeat(right));
+        content.push(row);
+    }
+    return StyleBlock::from_lines(content);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(block: StyleBlock) -> Vec<String> {
+        return block.finalize().lines().map(str::to_owned).collect();
+    }
+
+    #[test]
+    fn join_horizontal_positions() {
+        let tall = || Style::new().render_to_block("one\ntwo\nthree");
+        let short = || Style::new().render_to_block("x");
+        let center = join_horizontal(Position::CENTER, &[tall(), short()]);
+        assert_eq!(rows(center), ["one   ", "two  x", "three "]);
+        let bottom = join_horizontal(Position::BOTTOM, &[tall(), short()]);
+        assert_eq!(rows(bottom), ["one   ", "two   ", "threex"]);
+        let numbers = Style::new().render_to_block("1\n2\n3\n4\n5");
+        let quarter = join_horizontal(Position::fraction(0.25), &[numbers, short()]);
+        assert_eq!(rows(quarter), ["1 ", "2x", "3 ", "4 ", "5 "]);
+    }
+
+    #[test]
+    fn join_vertical_positions() {
+        let wide = || Style::new().render_to_block("abcd");
+        let narrow = || Style::new().render_to_block("x");
+        let center = join_vertical(Position::CENTER, &[wide(), narrow()]);
+        assert_eq!(rows(center), ["abcd", " x  "]);
+        let right = join_vertical(Position::RIGHT, &[wide(), narrow()]);
+        assert_eq!(rows(right), ["abcd", "   x"]);
+    }
+
+    #[test]
+    fn join_blocks_with_their_own_frame() {
+        let framed = || {
+            Style::new()
+                .render_to_block("a")
+                .border(BorderStyle::normal(), &[])
+                .margin(&[0, 1])
+        };
+        let plain = || Style::new().render_to_block("b");
+        let right = framed().join_right(plain());
+        assert_eq!(rows(right), [" ┌─┐ b", " │a│  ", " └─┘  "]);
+        let bottom = framed().join_bottom(plain());
+        assert_eq!(rows(bottom), [" ┌─┐ ", " │a│ ", " └─┘ ", "b    "]);
+    }
+}
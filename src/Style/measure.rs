@@ -0,0 +1,214 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+const ESC: char = '\u{1b}';
+const BEL: char = '\u{7}';
+/// Number of spaces a tab is expanded to
+const TAB_WIDTH: usize = 4;
+
+/// Display width of the widest line in a string
+///
+/// Escape sequences (SGR colors, OSC hyperlinks ...) are skipped,
+/// so this could be used on already rendered output
+///
+/// # Example
+///
+/// ```
+/// # use ripgross::Style::*;
+/// let line = Style::new().bold().render("Hello");
+/// assert_eq!(width(&line), 5);
+/// ```
+pub fn width(text: &str) -> usize {
+    return text.split('\n').map(display_width).max().unwrap_or(0);
+}
+
+/// Number of lines in a string
+///
+/// A single trailing newline, as appended by `StyleBlock::finalize`, does not count as a line
+pub fn height(text: &str) -> usize {
+    let text = text.strip_suffix('\n').unwrap_or(text);
+    return text.matches('\n').count() + 1;
+}
+
+/// Width and height of a string, see `width` and `height`
+pub fn size(text: &str) -> (usize, usize) {
+    return (width(text), height(text));
+}
+
+/// Cut every line of a string down to `cols` display columns
+///
+/// `tail` (e.g. "…") is put at the end of every line that has been cut and counts into `cols`.
+/// Escape sequences are never cut and those after the cut point are kept,
+/// so styles opened before the cut are still reset.
+pub fn truncate(text: &str, cols: usize, tail: &str) -> String {
+    let lines: Vec<String> = text
+        .split('\n')
+        .map(|line| truncate_line(line, cols, tail))
+        .collect();
+    return lines.join("\n");
+}
+
+fn truncate_line(line: &str, cols: usize, tail: &str) -> String {
+    if display_width(line) <= cols {
+        return line.to_owned();
+    }
+    let tail_width = display_width(tail);
+    let (limit, tail) = if tail_width > cols {
+        (cols, "")
+    } else {
+        (cols - tail_width, tail)
+    };
+    let mut result = String::new();
+    let mut line_width = 0;
+    let mut cut = false;
+    for (is_escape, segment) in split_escapes(line) {
+        if is_escape {
+            result.push_str(segment);
+            continue;
+        }
+        if cut {
+            continue;
+        }
+        for grapheme in segment.graphemes(true) {
+            let cluster_width = grapheme_width(grapheme);
+            if line_width + cluster_width > limit {
+                result.push_str(tail);
+                cut = true;
+                break;
+            }
+            result.push_str(grapheme);
+            line_width += cluster_width;
+        }
+    }
+    return result;
+}
+
+/// Number of terminal columns needed to display the text
+///
+/// Grapheme clusters are measured as a unit:
+/// East Asian wide characters and emoji take 2 columns,
+/// combining marks and zero-width joiners take none.
+/// Escape sequences take no space.
+pub(crate) fn display_width(text: &str) -> usize {
+    return split_escapes(text)
+        .into_iter()
+        .filter(|(is_escape, _)| !is_escape)
+        .map(|(_, segment)| segment.graphemes(true).map(grapheme_width).sum::<usize>())
+        .sum();
+}
+
+/// Display width of the widest grapheme cluster, the narrowest a text can be wrapped to
+pub(crate) fn widest_grapheme(text: &str) -> usize {
+    return split_escapes(text)
+        .into_iter()
+        .filter(|(is_escape, _)| !is_escape)
+        .flat_map(|(_, segment)| segment.graphemes(true).map(grapheme_width))
+        .max()
+        .unwrap_or(0);
+}
+
+/// Replace tabs with spaces, terminals would otherwise draw them to their own tab stops
+pub(crate) fn expand_tabs(text: &str) -> String {
+    return text.replace('\t', &" ".repeat(TAB_WIDTH));
+}
+
+/// Number of terminal columns needed to display a single grapheme cluster
+///
+/// A tab counts as the spaces it is expanded to
+pub(crate) fn grapheme_width(grapheme: &str) -> usize {
+    if grapheme == "\t" {
+        return TAB_WIDTH;
+    }
+    return UnicodeWidthStr::width(grapheme).min(2);
+}
+
+/// Split text into runs of printable text and escape sequences
+///
+/// Returns `(true, sequence)` for escape sequences and `(false, text)` for everything else.
+/// Recognizes CSI (`ESC [ ... final`), OSC (`ESC ] ... BEL` or `ESC ] ... ESC \`)
+/// and two-character escapes.
+pub(crate) fn split_escapes(text: &str) -> Vec<(bool, &str)> {
+    let mut segments: Vec<(bool, &str)> = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        if c != ESC {
+            continue;
+        }
+        if index > start {
+            segments.push((false, &text[start..index]));
+        }
+        let mut end = text.len();
+        match chars.next() {
+            Some((_, '[')) => {
+                for (i, c) in chars.by_ref() {
+                    if ('\u{40}'..='\u{7e}').contains(&c) {
+                        end = i + c.len_utf8();
+                        break;
+                    }
+                }
+            }
+            Some((_, ']')) => {
+                while let Some((i, c)) = chars.next() {
+                    if c == BEL {
+                        end = i + c.len_utf8();
+                        break;
+                    }
+                    if c == ESC {
+                        if let Some((i, '\\')) = chars.peek().copied() {
+                            chars.next();
+                            end = i + 1;
+                            break;
+                        }
+                    }
+                }
+            }
+            Some((i, c)) => end = i + c.len_utf8(),
+            None => (),
+        }
+        segments.push((true, &text[index..end]));
+        start = end;
+    }
+    if start < text.len() {
+        segments.push((false, &text[start..]));
+    }
+    return segments;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measure_display_width() {
+        assert_eq!(display_width("hello"), 5);
+        assert_eq!(display_width("a\tb"), 6);
+        assert_eq!(display_width("你好"), 4);
+        assert_eq!(display_width("e\u{301}"), 1);
+        assert_eq!(display_width("👩\u{200D}💻"), 2);
+        assert_eq!(display_width("❤\u{FE0F}"), 2);
+        assert_eq!(display_width("🇺🇸"), 2);
+        assert_eq!(display_width("\u{1b}[1;31mred\u{1b}[0m"), 3);
+        assert_eq!(
+            display_width("\u{1b}]8;;https://example.com\u{1b}\\link\u{1b}]8;;\u{1b}\\"),
+            4
+        );
+    }
+
+    #[test]
+    fn truncate_styled_text() {
+        let styled = "\u{1b}[1mhello world\u{1b}[0m";
+        assert_eq!(truncate(styled, 6, "…"), "\u{1b}[1mhello…\u{1b}[0m");
+        assert_eq!(truncate("你好世界", 5, ""), "你好");
+        assert_eq!(size("ab\ncde\n"), (3, 2));
+    }
+
+    #[test]
+    fn expand_tabs_in_blocks() {
+        let block = crate::Style::Style::new()
+            .render_to_block("a\tb")
+            .border_sides(&[true]);
+        assert_eq!(block.get_width(), 8);
+        assert_eq!(block.finalize().lines().nth(1), Some("│a    b│"));
+    }
+}
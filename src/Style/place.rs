@@ -0,0 +1,167 @@
+use super::border::horizontal_edge;
+use super::*;
+
+/// Look of the filler whitespace added by `place`
+///
+/// # Example
+///
+/// ```
+/// # use ripgross::Style::*;
+/// let dots = Whitespace::new().chars("·").foreground_rgb(80, 80, 80);
+/// let block = Style::new().render_to_block("Hello").set_width(5);
+/// let placed = place(11, 3, Position::CENTER, Position::CENTER, &block, &dots);
+/// assert_eq!(placed.get_width(), 11);
+/// assert_eq!(placed.get_height(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Whitespace {
+    chars: String,
+    style: Style,
+}
+
+impl Whitespace {
+    /// Plain spaces
+    pub fn new() -> Whitespace {
+        return Whitespace {
+            chars: " ".to_owned(),
+            style: Style::new(),
+        };
+    }
+    /// Fill with a repeating pattern instead of spaces
+    pub fn chars(mut self, pattern: &str) -> Self {
+        self.chars = pattern.to_owned();
+        return self;
+    }
+    pub fn foreground(mut self, color: impl Into<TerminalColor>) -> Self {
+        self.style = self.style.foreground(color);
+        return self;
+    }
+    pub fn background(mut self, color: impl Into<TerminalColor>) -> Self {
+        self.style = self.style.background(color);
+        return self;
+    }
+    pub fn foreground_rgb(self, r: u8, g: u8, b: u8) -> Self {
+        return self.foreground(TerminalColor::Rgb(r, g, b));
+    }
+    pub fn background_rgb(self, r: u8, g: u8, b: u8) -> Self {
+        return self.background(TerminalColor::Rgb(r, g, b));
+    }
+    /// Render the filler with `renderer` instead of the default renderer
+    pub fn renderer(mut self, renderer: &Renderer) -> Self {
+        self.style = self.style.renderer(renderer);
+        return self;
+    }
+    /// Filler `width` columns wide
+    fn render(&self, width: usize) -> String {
+        if width == 0 {
+            return String::new();
+        }
+        return self
+            .style
+            .line_text_decoration(horizontal_edge(&self.chars, width));
+    }
+}
+
+impl Default for Whitespace {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+/// Place a block inside a `width` x `height` area
+///
+/// The block is positioned with `horizontal` and `vertical`,
+/// the rest of the area is filled with `whitespace`.
+/// A block larger than the area is left as is along that axis
+pub fn place(
+    width: usize,
+    height: usize,
+    horizontal: Position,
+    vertical: Position,
+    block: &StyleBlock,
+    whitespace: &Whitespace,
+) -> StyleBlock {
+    let placed = place_horizontal(width, horizontal, block, whitespace);
+    return place_vertical(height, vertical, &placed, whitespace);
+}
+
+/// Place a block inside an area `width` columns wide, see `place`
+pub fn place_horizontal(
+    width: usize,
+    position: Position,
+    block: &StyleBlock,
+    whitespace: &Whitespace,
+) -> StyleBlock {
+    let lines = block.render_lines();
+    let block_width = lines
+        .iter()
+        .map(|line| display_width(line))
+        .max()
+        .unwrap_or(0);
+    let gap = width.saturating_sub(block_width);
+    let mut content: Vec<String> = Vec::new();
+    for line in lines {
+        let (left, right) = position.split(gap + block_width - display_width(&line));
+        let mut row = whitespace.render(left);
+        row.push_str(&line);
+        row.push_str(&whitespace.render(right));
+        content.push(row);
+    }
+    return StyleBlock::from_lines(content);
+}
+
+/// Place a block inside an area `height` lines high, see `place`
+pub fn place_vertical(
+    height: usize,
+    position: Position,
+    block: &StyleBlock,
+    whitespace: &Whitespace,
+) -> StyleBlock {
+    let lines = block.render_lines();
+    let block_width = lines
+        .iter()
+        .map(|line| display_width(line))
+        .max()
+        .unwrap_or(0);
+    let (top, bottom) = position.split(height.saturating_sub(lines.len()));
+    let mut content: Vec<String> = Vec::new();
+    for _ in 0..top {
+        content.push(whitespace.render(block_width));
+    }
+    for line in lines {
+        let fill = block_width - display_width(&line);
+        content.push(line + &whitespace.render(fill));
+    }
+    for _ in 0..bottom {
+        content.push(whitespace.render(block_width));
+    }
+    return StyleBlock::from_lines(content);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn place_with_pattern() {
+        let renderer = Renderer::new(std::io::sink());
+        renderer.set_color_profile(ColorProfile::TrueColor);
+        let dots = Whitespace::new()
+            .chars("·")
+            .foreground_rgb(80, 80, 80)
+            .renderer(&renderer);
+        let block = Style::new().render_to_block("ab");
+        let placed = place(7, 3, Position::CENTER, Position::BOTTOM, &block, &dots);
+        let fill = |width: usize| format!("\u{1b}[38;2;80;80;80m{}\u{1b}[0m", "·".repeat(width));
+        let expected = [fill(7), fill(7), format!("{}ab{}", fill(2), fill(3))];
+        assert_eq!(placed.finalize().lines().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn place_larger_block() {
+        let dots = Whitespace::new().chars("·");
+        let block = Style::new().render_to_block("abcd\nx");
+        let placed = place(2, 1, Position::CENTER, Position::CENTER, &block, &dots);
+        assert_eq!(placed.finalize(), "abcd\nx   \n");
+    }
+}
@@ -0,0 +1,43 @@
+/// Position along an axis, as a fraction from 0.0 (top / left) to 1.0 (bottom / right)
+///
+/// # Example
+///
+/// ```
+/// # use ripgross::Style::*;
+/// assert_eq!(Position::CENTER, Position::fraction(0.5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Position(f64);
+
+impl Position {
+    pub const TOP: Position = Position(0.0);
+    pub const BOTTOM: Position = Position(1.0);
+    pub const LEFT: Position = Position(0.0);
+    pub const RIGHT: Position = Position(1.0);
+    pub const CENTER: Position = Position(0.5);
+
+    /// Create a position from a fraction, clamped into 0.0 ..= 1.0
+    pub fn fraction(value: f64) -> Position {
+        if value.is_nan() {
+            return Position::CENTER;
+        }
+        return Position(value.clamp(0.0, 1.0));
+    }
+    /// The position as a fraction from 0.0 to 1.0
+    pub fn value(&self) -> f64 {
+        return self.0;
+    }
+    /// Split `gap` filler cells into the share before and after the positioned content
+    ///
+    /// When the gap can't be split evenly, the extra cell goes after the content
+    pub(crate) fn split(&self, gap: usize) -> (usize, usize) {
+        let before = ((gap as f64) * self.0).floor() as usize;
+        return (before, gap - before);
+    }
+}
+
+impl From<f64> for Position {
+    fn from(value: f64) -> Self {
+        return Position::fraction(value);
+    }
+}
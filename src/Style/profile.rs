@@ -0,0 +1,211 @@
+use super::TerminalColor;
+use std::io::IsTerminal;
+
+/// Color capability of the output terminal, from the least to the most capable
+///
+/// Colors are converted to the nearest color the profile supports when rendering,
+/// `Ascii` renders plain text without any escape sequence
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorProfile {
+    /// No colors nor decorators
+    Ascii,
+    /// The 16 basic ANSI colors
+    Ansi,
+    /// The 256 colors palette
+    Ansi256,
+    /// 24-bit colors
+    TrueColor,
+}
+
+/// `COLORFGBG` is set as `foreground;background` palette indices by some terminals,
+/// white (7) and bright colors other than dark gray (8) are light backgrounds
+pub(crate) fn detect_dark_background(var: impl Fn(&str) -> Option<String>) -> bool {
+    let background = var("COLORFGBG")
+        .and_then(|value| value.rsplit(';').next().map(str::to_owned))
+        .and_then(|value| value.trim().parse::<u8>().ok());
+    return !matches!(background, Some(7) | Some(9..=15));
+}
+
+impl ColorProfile {
+    /// Detect the profile of stdout from the environment
+    ///
+    /// See `from_env` for the rules
+    pub fn detect() -> ColorProfile {
+        return Self::from_env(std::io::stdout().is_terminal());
+    }
+    /// Detect the profile of an output from the environment
+    ///
+    /// - `NO_COLOR` set to any non-empty value turns colors off
+    /// - outputs other than a terminal get no colors, unless `CLICOLOR_FORCE` is set
+    ///   to a non-empty value other than `0`
+    /// - `COLORTERM=truecolor` or `24bit` gives true colors
+    /// - otherwise `TERM` decides: `dumb` gets no colors, `*-256color` gets 256 colors,
+    ///   and any other terminal gets the 16 ANSI colors
+    pub fn from_env(is_terminal: bool) -> ColorProfile {
+        return Self::from_vars(is_terminal, |key| std::env::var(key).ok());
+    }
+    fn from_vars(is_terminal: bool, var: impl Fn(&str) -> Option<String>) -> ColorProfile {
+        let is_set = |key: &str| var(key).is_some_and(|value| !value.is_empty());
+        if is_set("NO_COLOR") {
+            return ColorProfile::Ascii;
+        }
+        let forced = var("CLICOLOR_FORCE").is_some_and(|value| !value.is_empty() && value != "0");
+        if !is_terminal && !forced {
+            return ColorProfile::Ascii;
+        }
+        let term = var("TERM").unwrap_or_default().to_ascii_lowercase();
+        let colorterm = var("COLORTERM").unwrap_or_default().to_ascii_lowercase();
+        let profile = if colorterm == "truecolor"
+            || colorterm == "24bit"
+            || term.contains("truecolor")
+            || term.contains("24bit")
+            || term.contains("direct")
+            || term.starts_with("xterm-kitty")
+            || term.starts_with("wezterm")
+            || term.starts_with("alacritty")
+        {
+            ColorProfile::TrueColor
+        } else if term.contains("256color") {
+            ColorProfile::Ansi256
+        } else if term == "dumb" || (term.is_empty() && colorterm.is_empty()) {
+            ColorProfile::Ascii
+        } else {
+            ColorProfile::Ansi
+        };
+        if forced && profile == ColorProfile::Ascii {
+            return ColorProfile::Ansi;
+        }
+        return profile;
+    }
+    /// Nearest color this profile could display, `None` for `Ascii`
+    ///
+    /// Adaptive colors are picked with `dark_background`,
+    /// complete colors use the value given for this profile
+    pub fn convert(&self, color: &TerminalColor, dark_background: bool) -> Option<TerminalColor> {
+        return match (self, color) {
+            (ColorProfile::Ascii, _) => None,
+            (_, TerminalColor::Adaptive(adaptive)) => {
+                let picked = if dark_background {
+                    &adaptive.dark
+                } else {
+                    &adaptive.light
+                };
+                self.convert(picked, dark_background)
+            }
+            (ColorProfile::TrueColor, TerminalColor::Complete(complete)) => {
+                let (r, g, b) = complete.true_color;
+                Some(TerminalColor::Rgb(r, g, b))
+            }
+            (ColorProfile::Ansi256, TerminalColor::Complete(complete)) => {
+                Some(TerminalColor::Ansi256(complete.ansi256))
+            }
+            (ColorProfile::Ansi, TerminalColor::Complete(complete)) => {
+                Some(TerminalColor::Ansi(complete.ansi))
+            }
+            (ColorProfile::TrueColor, color) => Some(color.clone()),
+            (ColorProfile::Ansi256, TerminalColor::Rgb(r, g, b)) => {
+                Some(TerminalColor::Ansi256(rgb_to_ansi256(*r, *g, *b)))
+            }
+            (ColorProfile::Ansi256, color) => Some(color.clone()),
+            (ColorProfile::Ansi, TerminalColor::Ansi(index)) if *index < 16 => Some(color.clone()),
+            (ColorProfile::Ansi, color) => {
+                let (r, g, b) = color.to_rgb();
+                Some(TerminalColor::Ansi(nearest(r, g, b, 0..16)))
+            }
+        };
+    }
+}
+
+/// Nearest color of the 256 colors palette, searched in the 6x6x6 cube and the gray ramp
+fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |value: u8| -> u8 {
+        if value < 48 {
+            0
+        } else if value < 115 {
+            1
+        } else {
+            (value - 35) / 40
+        }
+    };
+    let cube = 16 + 36 * level(r) + 6 * level(g) + level(b);
+    let average = (r as u16 + g as u16 + b as u16) / 3;
+    let gray = if average > 238 {
+        255
+    } else {
+        232 + (average.saturating_sub(3) / 10) as u8
+    };
+    return nearest(r, g, b, [cube, gray]);
+}
+
+/// Palette index from `candidates` closest to the color, with the "redmean" weighted distance
+fn nearest(r: u8, g: u8, b: u8, candidates: impl IntoIterator<Item = u8>) -> u8 {
+    let distance = |index: u8| -> i64 {
+        let (r2, g2, b2) = TerminalColor::Ansi256(index).to_rgb();
+        let mean = (r as i64 + r2 as i64) / 2;
+        let dr = r as i64 - r2 as i64;
+        let dg = g as i64 - g2 as i64;
+        let db = b as i64 - b2 as i64;
+        return (((512 + mean) * dr * dr) >> 8) + 4 * dg * dg + (((767 - mean) * db * db) >> 8);
+    };
+    return candidates
+        .into_iter()
+        .min_by_key(|index| distance(*index))
+        .unwrap_or(0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Style::AdaptiveColor;
+
+    #[test]
+    fn detect_profile_from_env() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |key: &str| {
+                vars.iter()
+                    .find(|(name, _)| *name == key)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        let truecolor = env(&[("TERM", "xterm-256color"), ("COLORTERM", "truecolor")]);
+        assert_eq!(
+            ColorProfile::from_vars(true, truecolor),
+            ColorProfile::TrueColor
+        );
+        let xterm = env(&[("TERM", "xterm-256color")]);
+        assert_eq!(ColorProfile::from_vars(true, xterm), ColorProfile::Ansi256);
+        assert_eq!(ColorProfile::from_vars(false, xterm), ColorProfile::Ascii);
+        let no_color = env(&[("TERM", "xterm-256color"), ("NO_COLOR", "1")]);
+        assert_eq!(ColorProfile::from_vars(true, no_color), ColorProfile::Ascii);
+        let forced = env(&[("CLICOLOR_FORCE", "1")]);
+        assert_eq!(ColorProfile::from_vars(false, forced), ColorProfile::Ansi);
+    }
+
+    #[test]
+    fn convert_to_nearest_color() {
+        let orange = TerminalColor::Rgb(255, 135, 0);
+        assert_eq!(
+            ColorProfile::Ansi256.convert(&orange, true),
+            Some(TerminalColor::Ansi256(208))
+        );
+        assert_eq!(
+            ColorProfile::Ansi.convert(&TerminalColor::Rgb(250, 10, 10), true),
+            Some(TerminalColor::Ansi(9))
+        );
+        assert_eq!(ColorProfile::Ascii.convert(&orange, true), None);
+        let adaptive = TerminalColor::from(AdaptiveColor {
+            light: TerminalColor::Ansi(0),
+            dark: orange.clone(),
+        });
+        assert_eq!(
+            ColorProfile::TrueColor.convert(&adaptive, false),
+            Some(TerminalColor::Ansi(0))
+        );
+        assert_eq!(
+            ColorProfile::TrueColor.convert(&adaptive, true),
+            Some(orange)
+        );
+        assert!(!detect_dark_background(|_| Some("0;15".to_owned())));
+        assert!(detect_dark_background(|_| None));
+    }
+}
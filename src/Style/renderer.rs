@@ -0,0 +1,148 @@
+use super::profile::detect_dark_background;
+use super::*;
+use std::fmt;
+use std::io::{IsTerminal, Write};
+use std::sync::{Arc, Mutex, RwLock};
+
+/// Output destination with its color profile and background darkness
+///
+/// Styles render with the default renderer, writing to stdout,
+/// unless they are created from or bound to another renderer.
+/// Clones share the same settings and output,
+/// so one renderer per client could be used to serve many terminals at once
+///
+/// # Example
+///
+/// ```
+/// # use ripgross::Style::*;
+/// let renderer = Renderer::new(Vec::<u8>::new());
+/// renderer.set_color_profile(ColorProfile::Ansi256);
+/// let line = renderer.new_style().foreground_rgb(255, 135, 0).render("orange");
+/// assert_eq!(line, "\u{1b}[38;5;208morange\u{1b}[0m");
+/// ```
+#[derive(Clone)]
+pub struct Renderer {
+    state: Arc<RendererState>,
+}
+
+struct RendererState {
+    color_profile: RwLock<ColorProfile>,
+    dark_background: RwLock<bool>,
+    output: Mutex<Box<dyn Write + Send>>,
+}
+
+static DEFAULT_RENDERER: RwLock<Option<Renderer>> = RwLock::new(None);
+
+/// Renderer used by styles not bound to any renderer, writing to stdout
+pub fn default_renderer() -> Renderer {
+    if let Some(renderer) = &*DEFAULT_RENDERER.read().unwrap_or_else(|e| e.into_inner()) {
+        return renderer.clone();
+    }
+    let mut default = DEFAULT_RENDERER.write().unwrap_or_else(|e| e.into_inner());
+    return default.get_or_insert_with(Renderer::stdout).clone();
+}
+
+/// Replace the default renderer
+pub fn set_default_renderer(renderer: Renderer) {
+    *DEFAULT_RENDERER.write().unwrap_or_else(|e| e.into_inner()) = Some(renderer);
+}
+
+/// Color profile of the default renderer
+pub fn color_profile() -> ColorProfile {
+    return default_renderer().color_profile();
+}
+
+/// Override the color profile of the default renderer
+pub fn set_color_profile(profile: ColorProfile) {
+    default_renderer().set_color_profile(profile);
+}
+
+/// Whether the background of the default renderer is dark, used to pick adaptive colors
+pub fn has_dark_background() -> bool {
+    return default_renderer().has_dark_background();
+}
+
+/// Override the background darkness of the default renderer
+pub fn set_has_dark_background(dark: bool) {
+    default_renderer().set_has_dark_background(dark);
+}
+
+impl Renderer {
+    /// Renderer writing to `output`
+    ///
+    /// Such an output is not a terminal, so colors are off unless forced by the environment,
+    /// set the profile of the remote terminal with `set_color_profile`
+    pub fn new(output: impl Write + Send + 'static) -> Renderer {
+        return Self::with_profile(output, ColorProfile::from_env(false));
+    }
+    /// Renderer writing to stdout, with a profile detected from the environment
+    pub fn stdout() -> Renderer {
+        let profile = ColorProfile::from_env(std::io::stdout().is_terminal());
+        return Self::with_profile(std::io::stdout(), profile);
+    }
+    /// Renderer writing to stderr, with a profile detected from the environment
+    pub fn stderr() -> Renderer {
+        let profile = ColorProfile::from_env(std::io::stderr().is_terminal());
+        return Self::with_profile(std::io::stderr(), profile);
+    }
+    fn with_profile(output: impl Write + Send + 'static, profile: ColorProfile) -> Renderer {
+        return Renderer {
+            state: Arc::new(RendererState {
+                color_profile: RwLock::new(profile),
+                dark_background: RwLock::new(detect_dark_background(|key| std::env::var(key).ok())),
+                output: Mutex::new(Box::new(output)),
+            }),
+        };
+    }
+    /// Create a style rendering with this renderer
+    pub fn new_style(&self) -> Style {
+        return Style::new().renderer(self);
+    }
+    pub fn color_profile(&self) -> ColorProfile {
+        return *self
+            .state
+            .color_profile
+            .read()
+            .unwrap_or_else(|e| e.into_inner());
+    }
+    pub fn set_color_profile(&self, profile: ColorProfile) {
+        *self
+            .state
+            .color_profile
+            .write()
+            .unwrap_or_else(|e| e.into_inner()) = profile;
+    }
+    /// Whether the background is dark, used to pick adaptive colors
+    ///
+    /// Detected from `COLORFGBG`, terminals not telling their background are assumed dark
+    pub fn has_dark_background(&self) -> bool {
+        return *self
+            .state
+            .dark_background
+            .read()
+            .unwrap_or_else(|e| e.into_inner());
+    }
+    pub fn set_has_dark_background(&self, dark: bool) {
+        *self
+            .state
+            .dark_background
+            .write()
+            .unwrap_or_else(|e| e.into_inner()) = dark;
+    }
+    /// Write rendered text to the output
+    pub fn write(&self, text: &str) -> std::io::Result<()> {
+        let mut output = self.state.output.lock().unwrap_or_else(|e| e.into_inner());
+        output.write_all(text.as_bytes())?;
+        return output.flush();
+    }
+}
+
+impl fmt::Debug for Renderer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return f
+            .debug_struct("Renderer")
+            .field("color_profile", &self.color_profile())
+            .field("dark_background", &self.has_dark_background())
+            .finish_non_exhaustive();
+    }
+}
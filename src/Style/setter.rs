@@ -2,16 +2,86 @@ use super::*;
 impl StyleBlock {
     /// Set text block width
     /// This is the width where border would be drawed
+    ///
+    /// Text wraps within the width minus padding.
+    /// Unset, the block is as wide as its widest line plus padding
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ripgross::Style::*;
+    /// let label = Style::new().render_to_block("OK").padding(&[0, 1]);
+    /// assert_eq!(label.get_width(), 4);
+    /// assert_eq!(label.set_width(10).get_width(), 10);
+    /// ```
     pub fn set_width(mut self, size: usize) -> Self {
-        self.width = size;
+        self.width = Some(size);
         return self;
     }
     /// Set text block height
     /// This is the height where border would be drawed
+    ///
+    /// Shorter content is padded following the vertical alignment,
+    /// taller content is clipped
     pub fn set_height(mut self, size: usize) -> Self {
-        self.height = size;
+        self.height = Some(size);
+        return self;
+    }
+    /// Clip the rendered block, border and margin included, to at most `size` columns
+    ///
+    /// Without a width set, text also wraps so the block fits within `size`
+    pub fn max_width(mut self, size: usize) -> Self {
+        self.max_width = Some(size);
+        return self;
+    }
+    /// Clip the rendered block, border and margin included, to at most `size` lines
+    pub fn max_height(mut self, size: usize) -> Self {
+        self.max_height = Some(size);
         return self;
     }
+    /// Replace the text of the block, keeping its style and layout
+    ///
+    /// Useful to render many strings with one template block, e.g. table cells
+    pub fn set_string(mut self, text: &str) -> Self {
+        self.raw_string = text.to_owned();
+        self.content = Vec::new();
+        self.paragraph_fixed = false;
+        return self;
+    }
+    /// Mark clipped content with `ellipsis` in place of the last visible line
+    ///
+    /// Only used when a fixed height is set
+    pub fn height_ellipsis(mut self, ellipsis: &str) -> Self {
+        self.height_ellipsis = Some(ellipsis.to_owned());
+        return self;
+    }
+    /// Set padding on several sides at once, following CSS shorthand:
+    ///
+    /// - `[all]`
+    /// - `[vertical, horizontal]`
+    /// - `[top, horizontal, bottom]`
+    /// - `[top, right, bottom, left]`
+    ///
+    /// Any other number of values is ignored
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ripgross::Style::*;
+    /// let block = StyleBlock::new().padding(&[1, 2]);
+    /// assert_eq!(block.get_padding_top(), 1);
+    /// assert_eq!(block.get_padding_left(), 2);
+    /// ```
+    pub fn padding(self, sizes: &[usize]) -> Self {
+        return match expand_sides(sizes) {
+            Some([top, right, bottom, left]) => self
+                .padding_top(top)
+                .padding_right(right)
+                .padding_bottom(bottom)
+                .padding_left(left),
+            None => self,
+        };
+    }
     /// Set top padding inside text block
     pub fn padding_top(mut self, size: usize) -> Self {
         self.layout_dict.insert(Layout::PaddingTop, size);
@@ -34,39 +104,266 @@ impl StyleBlock {
     }
     /// Set text alignment to START
     pub fn align_start(mut self) -> Self {
-        self.horizontal_alignment = Alignment::Start;
+        self.horizontal_alignment = Some(Alignment::Start);
         return self;
     }
     /// Set text alignment to CENTER
     pub fn align_center(mut self) -> Self {
-        self.horizontal_alignment = Alignment::Center;
+        self.horizontal_alignment = Some(Alignment::Center);
         return self;
     }
     /// Set text alignment to END
     pub fn align_end(mut self) -> Self {
-        self.horizontal_alignment = Alignment::End;
+        self.horizontal_alignment = Some(Alignment::End);
+        return self;
+    }
+    /// Set vertical alignment to TOP
+    ///
+    /// Only used when a fixed height is set
+    pub fn align_top(mut self) -> Self {
+        self.vertical_alignment = Some(Alignment::Start);
+        return self;
+    }
+    /// Set vertical alignment to MIDDLE
+    ///
+    /// Only used when a fixed height is set
+    pub fn align_middle(mut self) -> Self {
+        self.vertical_alignment = Some(Alignment::Center);
+        return self;
+    }
+    /// Set vertical alignment to BOTTOM
+    ///
+    /// Only used when a fixed height is set
+    pub fn align_bottom(mut self) -> Self {
+        self.vertical_alignment = Some(Alignment::End);
         return self;
     }
     /// Set left border
     pub fn border_left(mut self) -> Self {
-        self.border_dict.insert(Border::BorderLeft);
+        self.border_dict.insert(Border::BorderLeft, true);
         return self;
     }
     /// Set right border
     pub fn border_right(mut self) -> Self {
-        self.border_dict.insert(Border::BorderRight);
+        self.border_dict.insert(Border::BorderRight, true);
         return self;
     }
     /// Set top border
     pub fn border_top(mut self) -> Self {
-        self.border_dict.insert(Border::BorderTop);
+        self.border_dict.insert(Border::BorderTop, true);
         return self;
     }
     /// Set bottom border
     pub fn border_bottom(mut self) -> Self {
-        self.border_dict.insert(Border::BorderBottom);
+        self.border_dict.insert(Border::BorderBottom, true);
+        return self;
+    }
+    /// Set or clear top border, e.g. from configuration data
+    pub fn set_border_top(mut self, enabled: bool) -> Self {
+        self.border_dict.insert(Border::BorderTop, enabled);
+        return self;
+    }
+    /// Set or clear right border, e.g. from configuration data
+    pub fn set_border_right(mut self, enabled: bool) -> Self {
+        self.border_dict.insert(Border::BorderRight, enabled);
+        return self;
+    }
+    /// Set or clear bottom border, e.g. from configuration data
+    pub fn set_border_bottom(mut self, enabled: bool) -> Self {
+        self.border_dict.insert(Border::BorderBottom, enabled);
+        return self;
+    }
+    /// Set or clear left border, e.g. from configuration data
+    pub fn set_border_left(mut self, enabled: bool) -> Self {
+        self.border_dict.insert(Border::BorderLeft, enabled);
+        return self;
+    }
+    /// Set the border glyphs and the sides to draw, like `border_sides`
+    ///
+    /// No sides means every side
+    pub fn border(self, style: BorderStyle, sides: &[bool]) -> Self {
+        let sides = if sides.is_empty() { &[true][..] } else { sides };
+        return self.border_style(style).border_sides(sides);
+    }
+    /// Enable or disable several border sides at once, with the same shorthand as `padding`
+    ///
+    /// Any other number of values is ignored
+    pub fn border_sides(self, sides: &[bool]) -> Self {
+        return match expand_sides(sides) {
+            Some([top, right, bottom, left]) => self
+                .set_border_top(top)
+                .set_border_right(right)
+                .set_border_bottom(bottom)
+                .set_border_left(left),
+            None => self,
+        };
+    }
+    /// Set the glyphs used to draw the border
+    ///
+    /// Default to `BorderStyle::normal()`
+    pub fn border_style(mut self, style: BorderStyle) -> Self {
+        self.border_style = Some(style);
+        return self;
+    }
+    /// Set foreground color of every border side
+    pub fn border_foreground(self, color: impl Into<TerminalColor>) -> Self {
+        let color = color.into();
+        return self
+            .border_top_foreground(color.clone())
+            .border_right_foreground(color.clone())
+            .border_bottom_foreground(color.clone())
+            .border_left_foreground(color);
+    }
+    /// Set background color of every border side
+    pub fn border_background(self, color: impl Into<TerminalColor>) -> Self {
+        let color = color.into();
+        return self
+            .border_top_background(color.clone())
+            .border_right_background(color.clone())
+            .border_bottom_background(color.clone())
+            .border_left_background(color);
+    }
+    pub fn border_foreground_rgb(self, r: u8, g: u8, b: u8) -> Self {
+        return self.border_foreground(TerminalColor::Rgb(r, g, b));
+    }
+    pub fn border_background_rgb(self, r: u8, g: u8, b: u8) -> Self {
+        return self.border_background(TerminalColor::Rgb(r, g, b));
+    }
+    /// Set foreground color of top border, corners included
+    pub fn border_top_foreground(mut self, color: impl Into<TerminalColor>) -> Self {
+        self.border_decoration_entry(Border::BorderTop)
+            .foreground_color = Some(color.into());
+        return self;
+    }
+    /// Set background color of top border, corners included
+    pub fn border_top_background(mut self, color: impl Into<TerminalColor>) -> Self {
+        self.border_decoration_entry(Border::BorderTop)
+            .background_color = Some(color.into());
         return self;
     }
+    pub fn border_top_foreground_rgb(self, r: u8, g: u8, b: u8) -> Self {
+        return self.border_top_foreground(TerminalColor::Rgb(r, g, b));
+    }
+    pub fn border_top_background_rgb(self, r: u8, g: u8, b: u8) -> Self {
+        return self.border_top_background(TerminalColor::Rgb(r, g, b));
+    }
+    /// Set foreground color of right border
+    pub fn border_right_foreground(mut self, color: impl Into<TerminalColor>) -> Self {
+        self.border_decoration_entry(Border::BorderRight)
+            .foreground_color = Some(color.into());
+        return self;
+    }
+    /// Set background color of right border
+    pub fn border_right_background(mut self, color: impl Into<TerminalColor>) -> Self {
+        self.border_decoration_entry(Border::BorderRight)
+            .background_color = Some(color.into());
+        return self;
+    }
+    pub fn border_right_foreground_rgb(self, r: u8, g: u8, b: u8) -> Self {
+        return self.border_right_foreground(TerminalColor::Rgb(r, g, b));
+    }
+    pub fn border_right_background_rgb(self, r: u8, g: u8, b: u8) -> Self {
+        return self.border_right_background(TerminalColor::Rgb(r, g, b));
+    }
+    /// Set foreground color of bottom border, corners included
+    pub fn border_bottom_foreground(mut self, color: impl Into<TerminalColor>) -> Self {
+        self.border_decoration_entry(Border::BorderBottom)
+            .foreground_color = Some(color.into());
+        return self;
+    }
+    /// Set background color of bottom border, corners included
+    pub fn border_bottom_background(mut self, color: impl Into<TerminalColor>) -> Self {
+        self.border_decoration_entry(Border::BorderBottom)
+            .background_color = Some(color.into());
+        return self;
+    }
+    pub fn border_bottom_foreground_rgb(self, r: u8, g: u8, b: u8) -> Self {
+        return self.border_bottom_foreground(TerminalColor::Rgb(r, g, b));
+    }
+    pub fn border_bottom_background_rgb(self, r: u8, g: u8, b: u8) -> Self {
+        return self.border_bottom_background(TerminalColor::Rgb(r, g, b));
+    }
+    /// Set foreground color of left border
+    pub fn border_left_foreground(mut self, color: impl Into<TerminalColor>) -> Self {
+        self.border_decoration_entry(Border::BorderLeft)
+            .foreground_color = Some(color.into());
+        return self;
+    }
+    /// Set background color of left border
+    pub fn border_left_background(mut self, color: impl Into<TerminalColor>) -> Self {
+        self.border_decoration_entry(Border::BorderLeft)
+            .background_color = Some(color.into());
+        return self;
+    }
+    pub fn border_left_foreground_rgb(self, r: u8, g: u8, b: u8) -> Self {
+        return self.border_left_foreground(TerminalColor::Rgb(r, g, b));
+    }
+    pub fn border_left_background_rgb(self, r: u8, g: u8, b: u8) -> Self {
+        return self.border_left_background(TerminalColor::Rgb(r, g, b));
+    }
+    /// Decorate every border side with the decorators and colors of `style`
+    ///
+    /// Decorators are added to the ones already set, colors replace the ones already set
+    pub fn border_decoration(self, style: &Style) -> Self {
+        return self
+            .border_top_decoration(style)
+            .border_right_decoration(style)
+            .border_bottom_decoration(style)
+            .border_left_decoration(style);
+    }
+    /// Decorate top border, corners included with the decorators and colors of `style`
+    pub fn border_top_decoration(mut self, style: &Style) -> Self {
+        self.border_decoration_entry(Border::BorderTop)
+            .overlay(style);
+        return self;
+    }
+    /// Decorate right border with the decorators and colors of `style`
+    pub fn border_right_decoration(mut self, style: &Style) -> Self {
+        self.border_decoration_entry(Border::BorderRight)
+            .overlay(style);
+        return self;
+    }
+    /// Decorate bottom border, corners included with the decorators and colors of `style`
+    pub fn border_bottom_decoration(mut self, style: &Style) -> Self {
+        self.border_decoration_entry(Border::BorderBottom)
+            .overlay(style);
+        return self;
+    }
+    /// Decorate left border with the decorators and colors of `style`
+    pub fn border_left_decoration(mut self, style: &Style) -> Self {
+        self.border_decoration_entry(Border::BorderLeft)
+            .overlay(style);
+        return self;
+    }
+    /// Set margins on several sides at once, with the same shorthand as `padding`
+    ///
+    /// Any other number of values is ignored
+    pub fn margin(self, sizes: &[usize]) -> Self {
+        return match expand_sides(sizes) {
+            Some([top, right, bottom, left]) => self
+                .margin_top(top)
+                .margin_right(right)
+                .margin_bottom(bottom)
+                .margin_left(left),
+            None => self,
+        };
+    }
+    /// Paint the whitespace inside the block with the background color, on by default
+    ///
+    /// This covers padding, alignment filler and the rows added to reach a fixed height,
+    /// so the block shows as a solid rectangle. Turn it off to only color the text
+    pub fn set_color_whitespace(mut self, enabled: bool) -> Self {
+        self.color_whitespace = Some(enabled);
+        return self;
+    }
+    /// Set the color of the margin area, margins are left unpainted otherwise
+    pub fn margin_background(mut self, color: impl Into<TerminalColor>) -> Self {
+        self.margin_background = Some(color.into());
+        return self;
+    }
+    pub fn margin_background_rgb(self, r: u8, g: u8, b: u8) -> Self {
+        return self.margin_background(TerminalColor::Rgb(r, g, b));
+    }
     /// Set top padding inside text block
     pub fn margin_top(mut self, size: usize) -> Self {
         self.layout_dict.insert(Layout::MarginTop, size);
@@ -89,44 +386,54 @@ impl StyleBlock {
     }
     /// Set Bold to text
     pub fn bold(mut self) -> Self {
-        self.style.decorator_dict.insert(Decorator::Bold);
+        self.style.decorator_dict.insert(Decorator::Bold, true);
         return self;
     }
     /// Set Italic to text
     pub fn italic(mut self) -> Self {
-        self.style.decorator_dict.insert(Decorator::Italic);
+        self.style.decorator_dict.insert(Decorator::Italic, true);
         return self;
     }
     /// Set Underline to text
     /// would Underline space if decorator_space is true
     pub fn underline(mut self) -> Self {
-        self.style.decorator_dict.insert(Decorator::Underline);
+        self.style.decorator_dict.insert(Decorator::Underline, true);
         return self;
     }
     /// Set Reverse to text
     pub fn reverse(mut self) -> Self {
-        self.style.decorator_dict.insert(Decorator::Reverse);
+        self.style.decorator_dict.insert(Decorator::Reverse, true);
         return self;
     }
     /// Set Strikethrough/Crossout to text
     /// Would stribethrough/Crossout space if decorator_space is true
     pub fn strikethrough(mut self) -> Self {
-        self.style.decorator_dict.insert(Decorator::Strikethrough);
+        self.style
+            .decorator_dict
+            .insert(Decorator::Strikethrough, true);
         return self;
     }
     /// Set Blink to text
     pub fn blink(mut self) -> Self {
-        self.style.decorator_dict.insert(Decorator::Blink);
+        self.style.decorator_dict.insert(Decorator::Blink, true);
         return self;
     }
-    pub fn foreground_rgb(mut self, r: u8, g: u8, b: u8) -> Self {
-        self.style.foreground_color = Some(Color::Rgb(r, g, b));
+    /// Set text color
+    pub fn foreground(mut self, color: impl Into<TerminalColor>) -> Self {
+        self.style.foreground_color = Some(color.into());
         return self;
     }
-    pub fn background_rgb(mut self, r: u8, g: u8, b: u8) -> Self {
-        self.style.background_color = Some(Color::Rgb(r, g, b));
+    /// Set text background color
+    pub fn background(mut self, color: impl Into<TerminalColor>) -> Self {
+        self.style.background_color = Some(color.into());
         return self;
     }
+    pub fn foreground_rgb(self, r: u8, g: u8, b: u8) -> Self {
+        return self.foreground(TerminalColor::Rgb(r, g, b));
+    }
+    pub fn background_rgb(self, r: u8, g: u8, b: u8) -> Self {
+        return self.background(TerminalColor::Rgb(r, g, b));
+    }
     pub fn reset_foreground(mut self) -> Self {
         self.style.foreground_color = None;
         return self;
@@ -135,55 +442,170 @@ impl StyleBlock {
         self.style.background_color = None;
         return self;
     }
+    /// Set or clear Bold, e.g. from configuration data
+    pub fn set_bold(mut self, enabled: bool) -> Self {
+        self.style.decorator_dict.insert(Decorator::Bold, enabled);
+        return self;
+    }
+    /// Set or clear Italic, e.g. from configuration data
+    pub fn set_italic(mut self, enabled: bool) -> Self {
+        self.style.decorator_dict.insert(Decorator::Italic, enabled);
+        return self;
+    }
+    /// Set or clear Underline, e.g. from configuration data
+    pub fn set_underline(mut self, enabled: bool) -> Self {
+        self.style
+            .decorator_dict
+            .insert(Decorator::Underline, enabled);
+        return self;
+    }
+    /// Set or clear Reverse, e.g. from configuration data
+    pub fn set_reverse(mut self, enabled: bool) -> Self {
+        self.style
+            .decorator_dict
+            .insert(Decorator::Reverse, enabled);
+        return self;
+    }
+    /// Set or clear Strikethrough/Crossout, e.g. from configuration data
+    pub fn set_strikethrough(mut self, enabled: bool) -> Self {
+        self.style
+            .decorator_dict
+            .insert(Decorator::Strikethrough, enabled);
+        return self;
+    }
+    /// Set or clear Blink, e.g. from configuration data
+    pub fn set_blink(mut self, enabled: bool) -> Self {
+        self.style.decorator_dict.insert(Decorator::Blink, enabled);
+        return self;
+    }
+    /// Set whether spaces are decorated, see `ignore_space`
+    pub fn set_decorated_space(mut self, enabled: bool) -> Self {
+        self.style.decorated_space = Some(enabled);
+        return self;
+    }
+    /// Render with `renderer` instead of the default renderer
+    ///
+    /// Borders are rendered with it as well
+    pub fn renderer(mut self, renderer: &Renderer) -> Self {
+        self.style.renderer = Some(renderer.clone());
+        return self;
+    }
     /// Set decorated_space = false
     ///
     /// ### This would aggregate space between words !
     pub fn ignore_space(mut self) -> Self {
-        self.style.decorated_space = false;
+        self.style.decorated_space = Some(false);
         return self;
     }
+    /// Copy the properties of `other` that are not set on this block
+    ///
+    /// Properties set on this block, even to their default value, are kept.
+    /// Text content is not inherited
+    pub fn inherit(mut self, other: &StyleBlock) -> Self {
+        self.style = self.style.inherit(&other.style);
+        for (layout, size) in other.layout_dict.iter() {
+            self.layout_dict.entry(layout.clone()).or_insert(*size);
+        }
+        for (side, enabled) in other.border_dict.iter() {
+            self.border_dict.entry(side.clone()).or_insert(*enabled);
+        }
+        for (side, style) in other.border_decoration_dict.iter() {
+            let inherited = match self.border_decoration_dict.remove(side) {
+                Some(own) => own.inherit(style),
+                None => style.clone(),
+            };
+            self.border_decoration_dict.insert(side.clone(), inherited);
+        }
+        if self.border_style.is_none() {
+            self.border_style = other.border_style.clone();
+        }
+        if self.horizontal_alignment.is_none() {
+            self.horizontal_alignment = other.horizontal_alignment;
+        }
+        if self.vertical_alignment.is_none() {
+            self.vertical_alignment = other.vertical_alignment;
+        }
+        if self.width.is_none() {
+            self.width = other.width;
+        }
+        if self.height.is_none() {
+            self.height = other.height;
+        }
+        if self.height_ellipsis.is_none() {
+            self.height_ellipsis = other.height_ellipsis.clone();
+        }
+        if self.margin_background.is_none() {
+            self.margin_background = other.margin_background.clone();
+        }
+        if self.color_whitespace.is_none() {
+            self.color_whitespace = other.color_whitespace;
+        }
+        if self.max_width.is_none() {
+            self.max_width = other.max_width;
+        }
+        if self.max_height.is_none() {
+            self.max_height = other.max_height;
+        }
+        return self;
+    }
+    fn border_decoration_entry(&mut self, side: Border) -> &mut Style {
+        return self.border_decoration_dict.entry(side).or_default();
+    }
 }
 impl Style {
+    /// Render with `renderer` instead of the default renderer
+    pub fn renderer(mut self, renderer: &Renderer) -> Self {
+        self.renderer = Some(renderer.clone());
+        return self;
+    }
     /// Set Bold to text
     pub fn bold(mut self) -> Self {
-        self.decorator_dict.insert(Decorator::Bold);
+        self.decorator_dict.insert(Decorator::Bold, true);
         return self;
     }
     /// Set Italic to text
     pub fn italic(mut self) -> Self {
-        self.decorator_dict.insert(Decorator::Italic);
+        self.decorator_dict.insert(Decorator::Italic, true);
         return self;
     }
     /// Set Underline to text
     /// would Underline space if decorator_space is true
     pub fn underline(mut self) -> Self {
-        self.decorator_dict.insert(Decorator::Underline);
+        self.decorator_dict.insert(Decorator::Underline, true);
         return self;
     }
     /// Set Reverse to text
     pub fn reverse(mut self) -> Self {
-        self.decorator_dict.insert(Decorator::Reverse);
+        self.decorator_dict.insert(Decorator::Reverse, true);
         return self;
     }
     /// Set Strikethrough/Crossout to text
     /// Would stribethrough/Crossout space if decorator_space is true
     pub fn strikethrough(mut self) -> Self {
-        self.decorator_dict.insert(Decorator::Strikethrough);
+        self.decorator_dict.insert(Decorator::Strikethrough, true);
         return self;
     }
     /// Set Blink to text
     pub fn blink(mut self) -> Self {
-        self.decorator_dict.insert(Decorator::Blink);
+        self.decorator_dict.insert(Decorator::Blink, true);
         return self;
     }
-    pub fn foreground_rgb(mut self, r: u8, g: u8, b: u8) -> Self {
-        self.foreground_color = Some(Color::Rgb(r, g, b));
+    /// Set text color
+    pub fn foreground(mut self, color: impl Into<TerminalColor>) -> Self {
+        self.foreground_color = Some(color.into());
         return self;
     }
-    pub fn background_rgb(mut self, r: u8, g: u8, b: u8) -> Self {
-        self.background_color = Some(Color::Rgb(r, g, b));
+    /// Set text background color
+    pub fn background(mut self, color: impl Into<TerminalColor>) -> Self {
+        self.background_color = Some(color.into());
         return self;
     }
+    pub fn foreground_rgb(self, r: u8, g: u8, b: u8) -> Self {
+        return self.foreground(TerminalColor::Rgb(r, g, b));
+    }
+    pub fn background_rgb(self, r: u8, g: u8, b: u8) -> Self {
+        return self.background(TerminalColor::Rgb(r, g, b));
+    }
     pub fn reset_foreground(mut self) -> Self {
         self.foreground_color = None;
         return self;
@@ -196,7 +618,127 @@ impl Style {
     ///
     /// ### This would aggregate space between words !
     pub fn ignore_space(mut self) -> Self {
-        self.decorated_space = false;
+        self.decorated_space = Some(false);
+        return self;
+    }
+    /// Set or clear Bold, e.g. from configuration data
+    pub fn set_bold(mut self, enabled: bool) -> Self {
+        self.decorator_dict.insert(Decorator::Bold, enabled);
+        return self;
+    }
+    /// Set or clear Italic, e.g. from configuration data
+    pub fn set_italic(mut self, enabled: bool) -> Self {
+        self.decorator_dict.insert(Decorator::Italic, enabled);
+        return self;
+    }
+    /// Set or clear Underline, e.g. from configuration data
+    pub fn set_underline(mut self, enabled: bool) -> Self {
+        self.decorator_dict.insert(Decorator::Underline, enabled);
         return self;
     }
+    /// Set or clear Reverse, e.g. from configuration data
+    pub fn set_reverse(mut self, enabled: bool) -> Self {
+        self.decorator_dict.insert(Decorator::Reverse, enabled);
+        return self;
+    }
+    /// Set or clear Strikethrough/Crossout, e.g. from configuration data
+    pub fn set_strikethrough(mut self, enabled: bool) -> Self {
+        self.decorator_dict
+            .insert(Decorator::Strikethrough, enabled);
+        return self;
+    }
+    /// Set or clear Blink, e.g. from configuration data
+    pub fn set_blink(mut self, enabled: bool) -> Self {
+        self.decorator_dict.insert(Decorator::Blink, enabled);
+        return self;
+    }
+    /// Set whether spaces are decorated, see `ignore_space`
+    pub fn set_decorated_space(mut self, enabled: bool) -> Self {
+        self.decorated_space = Some(enabled);
+        return self;
+    }
+    /// Copy the properties of `other` that are not set on this style
+    ///
+    /// Properties set on this style, even to their default value, are kept
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ripgross::Style::*;
+    /// let base = Style::new().bold().italic().foreground_rgb(81, 105, 151);
+    /// let link = Style::new().underline().set_italic(false).inherit(&base);
+    /// assert!(link.is_bold());
+    /// assert!(link.is_underline());
+    /// assert!(!link.is_italic());
+    /// ```
+    pub fn inherit(mut self, other: &Style) -> Self {
+        for (decorator, enabled) in other.decorator_dict.iter() {
+            self.decorator_dict
+                .entry(decorator.clone())
+                .or_insert(*enabled);
+        }
+        if self.decorated_space.is_none() {
+            self.decorated_space = other.decorated_space;
+        }
+        if self.foreground_color.is_none() {
+            self.foreground_color = other.foreground_color.clone();
+        }
+        if self.background_color.is_none() {
+            self.background_color = other.background_color.clone();
+        }
+        if self.renderer.is_none() {
+            self.renderer = other.renderer.clone();
+        }
+        return self;
+    }
+}
+
+/// Expand 1 to 4 CSS shorthand values into `[top, right, bottom, left]`
+fn expand_sides<T: Copy>(values: &[T]) -> Option<[T; 4]> {
+    return match *values {
+        [all] => Some([all, all, all, all]),
+        [vertical, horizontal] => Some([vertical, horizontal, vertical, horizontal]),
+        [top, horizontal, bottom] => Some([top, horizontal, bottom, horizontal]),
+        [top, right, bottom, left] => Some([top, right, bottom, left]),
+        _ => None,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paddings(block: &StyleBlock) -> [usize; 4] {
+        return [
+            block.get_padding_top(),
+            block.get_padding_right(),
+            block.get_padding_bottom(),
+            block.get_padding_left(),
+        ];
+    }
+
+    #[test]
+    fn expand_shorthand_sides() {
+        assert_eq!(expand_sides(&[1]), Some([1, 1, 1, 1]));
+        assert_eq!(expand_sides(&[1, 2]), Some([1, 2, 1, 2]));
+        assert_eq!(expand_sides(&[1, 2, 3]), Some([1, 2, 3, 2]));
+        assert_eq!(expand_sides(&[1, 2, 3, 4]), Some([1, 2, 3, 4]));
+        assert_eq!(expand_sides::<usize>(&[]), None);
+        assert_eq!(expand_sides(&[1, 2, 3, 4, 5]), None);
+
+        assert_eq!(
+            paddings(&StyleBlock::new().padding(&[1, 2, 3])),
+            [1, 2, 3, 2]
+        );
+        let set = StyleBlock::new().padding(&[1, 2, 3, 4]);
+        assert_eq!(paddings(&set.clone().padding(&[])), [1, 2, 3, 4]);
+        assert_eq!(paddings(&set.padding(&[9, 9, 9, 9, 9])), [1, 2, 3, 4]);
+        let margin = StyleBlock::new().margin(&[1, 2, 3]);
+        assert_eq!(margin.get_margin_left(), 2);
+        assert_eq!(margin.get_margin_bottom(), 3);
+        let sides = StyleBlock::new().border_sides(&[true, false, true]);
+        assert!(sides.get_border_top() && sides.get_border_bottom());
+        assert!(!sides.get_border_left() && !sides.get_border_right());
+        assert!(!StyleBlock::new().border_sides(&[true; 5]).get_border_top());
+    }
 }
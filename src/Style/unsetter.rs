@@ -0,0 +1,409 @@
+use super::*;
+impl StyleBlock {
+    /// Unset text block width, the width follows the content again
+    pub fn unset_width(mut self) -> Self {
+        self.width = None;
+        return self;
+    }
+    /// Unset text block height, the height follows the content again
+    pub fn unset_height(mut self) -> Self {
+        self.height = None;
+        return self;
+    }
+    /// Unset the ellipsis marking clipped content
+    pub fn unset_height_ellipsis(mut self) -> Self {
+        self.height_ellipsis = None;
+        return self;
+    }
+    /// Unset top padding
+    pub fn unset_padding_top(mut self) -> Self {
+        self.layout_dict.remove(&Layout::PaddingTop);
+        return self;
+    }
+    /// Unset right padding
+    pub fn unset_padding_right(mut self) -> Self {
+        self.layout_dict.remove(&Layout::PaddingRight);
+        return self;
+    }
+    /// Unset bottom padding
+    pub fn unset_padding_bottom(mut self) -> Self {
+        self.layout_dict.remove(&Layout::PaddingBottom);
+        return self;
+    }
+    /// Unset left padding
+    pub fn unset_padding_left(mut self) -> Self {
+        self.layout_dict.remove(&Layout::PaddingLeft);
+        return self;
+    }
+    /// Unset top margin
+    pub fn unset_margin_top(mut self) -> Self {
+        self.layout_dict.remove(&Layout::MarginTop);
+        return self;
+    }
+    /// Unset right margin
+    pub fn unset_margin_right(mut self) -> Self {
+        self.layout_dict.remove(&Layout::MarginRight);
+        return self;
+    }
+    /// Unset bottom margin
+    pub fn unset_margin_bottom(mut self) -> Self {
+        self.layout_dict.remove(&Layout::MarginBottom);
+        return self;
+    }
+    /// Unset left margin
+    pub fn unset_margin_left(mut self) -> Self {
+        self.layout_dict.remove(&Layout::MarginLeft);
+        return self;
+    }
+    /// Unset maximum width, the block is no longer clipped horizontally
+    pub fn unset_max_width(mut self) -> Self {
+        self.max_width = None;
+        return self;
+    }
+    /// Unset maximum height, the block is no longer clipped vertically
+    pub fn unset_max_height(mut self) -> Self {
+        self.max_height = None;
+        return self;
+    }
+    /// Unset whitespace coloring, back to painting whitespace with the background
+    pub fn unset_color_whitespace(mut self) -> Self {
+        self.color_whitespace = None;
+        return self;
+    }
+    /// Unset margin background, margins are left unpainted
+    pub fn unset_margin_background(mut self) -> Self {
+        self.margin_background = None;
+        return self;
+    }
+    /// Unset horizontal alignment, back to START
+    pub fn unset_horizontal_alignment(mut self) -> Self {
+        self.horizontal_alignment = None;
+        return self;
+    }
+    /// Unset vertical alignment, back to TOP
+    pub fn unset_vertical_alignment(mut self) -> Self {
+        self.vertical_alignment = None;
+        return self;
+    }
+    /// Unset top border
+    pub fn unset_border_top(mut self) -> Self {
+        self.border_dict.remove(&Border::BorderTop);
+        return self;
+    }
+    /// Unset right border
+    pub fn unset_border_right(mut self) -> Self {
+        self.border_dict.remove(&Border::BorderRight);
+        return self;
+    }
+    /// Unset bottom border
+    pub fn unset_border_bottom(mut self) -> Self {
+        self.border_dict.remove(&Border::BorderBottom);
+        return self;
+    }
+    /// Unset left border
+    pub fn unset_border_left(mut self) -> Self {
+        self.border_dict.remove(&Border::BorderLeft);
+        return self;
+    }
+    /// Unset border glyphs, back to `BorderStyle::normal()`
+    pub fn unset_border_style(mut self) -> Self {
+        self.border_style = None;
+        return self;
+    }
+    /// Unset foreground color of every border side
+    pub fn unset_border_foreground(self) -> Self {
+        return self
+            .unset_border_top_foreground()
+            .unset_border_right_foreground()
+            .unset_border_bottom_foreground()
+            .unset_border_left_foreground();
+    }
+    /// Unset background color of every border side
+    pub fn unset_border_background(self) -> Self {
+        return self
+            .unset_border_top_background()
+            .unset_border_right_background()
+            .unset_border_bottom_background()
+            .unset_border_left_background();
+    }
+    /// Unset foreground color of top border
+    pub fn unset_border_top_foreground(mut self) -> Self {
+        if let Some(style) = self.border_decoration_dict.get_mut(&Border::BorderTop) {
+            style.foreground_color = None;
+        }
+        return self;
+    }
+    /// Unset background color of top border
+    pub fn unset_border_top_background(mut self) -> Self {
+        if let Some(style) = self.border_decoration_dict.get_mut(&Border::BorderTop) {
+            style.background_color = None;
+        }
+        return self;
+    }
+    /// Unset foreground color of right border
+    pub fn unset_border_right_foreground(mut self) -> Self {
+        if let Some(style) = self.border_decoration_dict.get_mut(&Border::BorderRight) {
+            style.foreground_color = None;
+        }
+        return self;
+    }
+    /// Unset background color of right border
+    pub fn unset_border_right_background(mut self) -> Self {
+        if let Some(style) = self.border_decoration_dict.get_mut(&Border::BorderRight) {
+            style.background_color = None;
+        }
+        return self;
+    }
+    /// Unset foreground color of bottom border
+    pub fn unset_border_bottom_foreground(mut self) -> Self {
+        if let Some(style) = self.border_decoration_dict.get_mut(&Border::BorderBottom) {
+            style.foreground_color = None;
+        }
+        return self;
+    }
+    /// Unset background color of bottom border
+    pub fn unset_border_bottom_background(mut self) -> Self {
+        if let Some(style) = self.border_decoration_dict.get_mut(&Border::BorderBottom) {
+            style.background_color = None;
+        }
+        return self;
+    }
+    /// Unset foreground color of left border
+    pub fn unset_border_left_foreground(mut self) -> Self {
+        if let Some(style) = self.border_decoration_dict.get_mut(&Border::BorderLeft) {
+            style.foreground_color = None;
+        }
+        return self;
+    }
+    /// Unset background color of left border
+    pub fn unset_border_left_background(mut self) -> Self {
+        if let Some(style) = self.border_decoration_dict.get_mut(&Border::BorderLeft) {
+            style.background_color = None;
+        }
+        return self;
+    }
+    /// Unset colors and decorators of every border side
+    pub fn unset_border_decoration(mut self) -> Self {
+        self.border_decoration_dict.clear();
+        return self;
+    }
+    /// Unset colors and decorators of top border
+    pub fn unset_border_top_decoration(mut self) -> Self {
+        self.border_decoration_dict.remove(&Border::BorderTop);
+        return self;
+    }
+    /// Unset colors and decorators of right border
+    pub fn unset_border_right_decoration(mut self) -> Self {
+        self.border_decoration_dict.remove(&Border::BorderRight);
+        return self;
+    }
+    /// Unset colors and decorators of bottom border
+    pub fn unset_border_bottom_decoration(mut self) -> Self {
+        self.border_decoration_dict.remove(&Border::BorderBottom);
+        return self;
+    }
+    /// Unset colors and decorators of left border
+    pub fn unset_border_left_decoration(mut self) -> Self {
+        self.border_decoration_dict.remove(&Border::BorderLeft);
+        return self;
+    }
+    /// Unset Bold
+    pub fn unset_bold(mut self) -> Self {
+        self.style.decorator_dict.remove(&Decorator::Bold);
+        return self;
+    }
+    /// Unset Italic
+    pub fn unset_italic(mut self) -> Self {
+        self.style.decorator_dict.remove(&Decorator::Italic);
+        return self;
+    }
+    /// Unset Underline
+    pub fn unset_underline(mut self) -> Self {
+        self.style.decorator_dict.remove(&Decorator::Underline);
+        return self;
+    }
+    /// Unset Reverse
+    pub fn unset_reverse(mut self) -> Self {
+        self.style.decorator_dict.remove(&Decorator::Reverse);
+        return self;
+    }
+    /// Unset Strikethrough/Crossout
+    pub fn unset_strikethrough(mut self) -> Self {
+        self.style.decorator_dict.remove(&Decorator::Strikethrough);
+        return self;
+    }
+    /// Unset Blink
+    pub fn unset_blink(mut self) -> Self {
+        self.style.decorator_dict.remove(&Decorator::Blink);
+        return self;
+    }
+    /// Unset text color
+    pub fn unset_foreground(mut self) -> Self {
+        self.style.foreground_color = None;
+        return self;
+    }
+    /// Unset text background color
+    pub fn unset_background(mut self) -> Self {
+        self.style.background_color = None;
+        return self;
+    }
+    /// Unset decorated_space, spaces are decorated again
+    pub fn unset_decorated_space(mut self) -> Self {
+        self.style.decorated_space = None;
+        return self;
+    }
+    /// Unset renderer, the default renderer is used again
+    pub fn unset_renderer(mut self) -> Self {
+        self.style.renderer = None;
+        return self;
+    }
+}
+
+impl Style {
+    /// Unset Bold
+    pub fn unset_bold(mut self) -> Self {
+        self.decorator_dict.remove(&Decorator::Bold);
+        return self;
+    }
+    /// Unset Italic
+    pub fn unset_italic(mut self) -> Self {
+        self.decorator_dict.remove(&Decorator::Italic);
+        return self;
+    }
+    /// Unset Underline
+    pub fn unset_underline(mut self) -> Self {
+        self.decorator_dict.remove(&Decorator::Underline);
+        return self;
+    }
+    /// Unset Reverse
+    pub fn unset_reverse(mut self) -> Self {
+        self.decorator_dict.remove(&Decorator::Reverse);
+        return self;
+    }
+    /// Unset Strikethrough/Crossout
+    pub fn unset_strikethrough(mut self) -> Self {
+        self.decorator_dict.remove(&Decorator::Strikethrough);
+        return self;
+    }
+    /// Unset Blink
+    pub fn unset_blink(mut self) -> Self {
+        self.decorator_dict.remove(&Decorator::Blink);
+        return self;
+    }
+    /// Unset text color
+    pub fn unset_foreground(mut self) -> Self {
+        self.foreground_color = None;
+        return self;
+    }
+    /// Unset text background color
+    pub fn unset_background(mut self) -> Self {
+        self.background_color = None;
+        return self;
+    }
+    /// Unset decorated_space, spaces are decorated again
+    pub fn unset_decorated_space(mut self) -> Self {
+        self.decorated_space = None;
+        return self;
+    }
+    /// Unset renderer, the default renderer is used again
+    pub fn unset_renderer(mut self) -> Self {
+        self.renderer = None;
+        return self;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unset_block_properties() {
+        let block = StyleBlock::new()
+            .set_width(8)
+            .set_height(3)
+            .height_ellipsis("…")
+            .padding(&[1, 2])
+            .margin(&[1, 2])
+            .border(BorderStyle::thick(), &[])
+            .border_top_foreground_rgb(200, 0, 0)
+            .max_width(6)
+            .align_center()
+            .set_color_whitespace(false)
+            .margin_background_rgb(0, 0, 200);
+        let unset = block
+            .unset_width()
+            .unset_height()
+            .unset_height_ellipsis()
+            .unset_padding_top()
+            .unset_padding_left()
+            .unset_margin_right()
+            .unset_margin_bottom()
+            .unset_border_top()
+            .unset_border_style()
+            .unset_border_top_foreground()
+            .unset_max_width()
+            .unset_horizontal_alignment()
+            .unset_color_whitespace()
+            .unset_margin_background();
+        assert_eq!(unset.get_block_width(), None);
+        assert_eq!(unset.get_block_height(), None);
+        assert_eq!(unset.get_height_ellipsis(), None);
+        assert_eq!(unset.get_padding_top(), 0);
+        assert_eq!(unset.get_padding_left(), 0);
+        assert_eq!(unset.get_padding_right(), 2);
+        assert_eq!(unset.get_margin_right(), 0);
+        assert_eq!(unset.get_margin_bottom(), 0);
+        assert_eq!(unset.get_margin_top(), 1);
+        assert!(!unset.get_border_top());
+        assert!(unset.get_border_bottom());
+        assert_eq!(unset.get_border_style(), BorderStyle::normal());
+        assert_eq!(unset.get_border_top_foreground(), None);
+        assert_eq!(unset.get_max_width(), None);
+        assert_eq!(unset.get_horizontal_alignment(), Alignment::Start);
+        assert!(unset.get_color_whitespace());
+        assert_eq!(unset.get_margin_background(), None);
+    }
+
+    #[test]
+    fn unset_style_properties() {
+        let style = Style::new()
+            .bold()
+            .italic()
+            .foreground_rgb(200, 0, 0)
+            .background_rgb(0, 0, 200)
+            .ignore_space();
+        let unset = style
+            .unset_bold()
+            .unset_foreground()
+            .unset_background()
+            .unset_decorated_space();
+        assert!(!unset.is_bold());
+        assert!(unset.is_italic());
+        assert_eq!(unset.get_foreground(), None);
+        assert_eq!(unset.get_background(), None);
+        assert!(unset.get_decorated_space());
+    }
+
+    #[test]
+    fn cleared_and_unset_with_inherit() {
+        let parent = Style::new().bold().italic();
+        let cleared = Style::new().set_bold(false).inherit(&parent);
+        assert!(!cleared.is_bold());
+        assert!(cleared.is_italic());
+        let unset = Style::new().bold().unset_bold().inherit(&parent);
+        assert!(unset.is_bold());
+
+        let parent_block = StyleBlock::new().bold().padding_top(1);
+        let cleared_block = StyleBlock::new().set_bold(false).inherit(&parent_block);
+        assert!(!cleared_block.is_bold());
+        assert_eq!(cleared_block.get_padding_top(), 1);
+        let unset_block = StyleBlock::new()
+            .bold()
+            .unset_bold()
+            .padding_top(2)
+            .unset_padding_top()
+            .inherit(&parent_block);
+        assert!(unset_block.is_bold());
+        assert_eq!(unset_block.get_padding_top(), 1);
+    }
+}
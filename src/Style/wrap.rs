@@ -0,0 +1,129 @@
+use super::measure::{display_width, grapheme_width, split_escapes};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Wrap text into lines no wider than `width` display columns
+///
+/// Existing `\n` are kept as hard breaks and every paragraph is wrapped on its own.
+/// Lines are broken on whitespace, the whitespace at a break point is dropped,
+/// and words longer than `width` are hard-broken.
+///
+/// Leading and inner whitespace is kept as is,
+/// trailing whitespace is kept as long as it fits in the line.
+pub(crate) fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for paragraph in text.split('\n') {
+        let paragraph = paragraph.strip_suffix('\r').unwrap_or(paragraph);
+        lines.append(&mut wrap_paragraph(paragraph, width));
+    }
+    return lines;
+}
+
+fn wrap_paragraph(paragraph: &str, width: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut line = String::new();
+    let mut line_width = 0;
+    let mut space = String::new();
+    for (is_space, token) in tokenize(paragraph) {
+        if is_space {
+            space.push_str(token);
+            continue;
+        }
+        let word_width = display_width(token);
+        let space_width = display_width(&space);
+        if line_width + space_width + word_width <= width {
+            line.push_str(&space);
+            line.push_str(token);
+            line_width += space_width + word_width;
+            space.clear();
+            continue;
+        }
+        // Break before the word, the whitespace at the break point is dropped
+        if line_width > 0 {
+            lines.push(line);
+            line = String::new();
+            line_width = 0;
+        }
+        space.clear();
+        if word_width <= width {
+            line.push_str(token);
+            line_width = word_width;
+            continue;
+        }
+        // Hard-break words that would never fit in a single line
+        // Escape sequences are kept whole
+        for (is_escape, segment) in split_escapes(token) {
+            if is_escape {
+                line.push_str(segment);
+                continue;
+            }
+            for grapheme in segment.graphemes(true) {
+                let cluster_width = grapheme_width(grapheme);
+                if line_width + cluster_width > width && line_width > 0 {
+                    lines.push(line);
+                    line = String::new();
+                    line_width = 0;
+                }
+                line.push_str(grapheme);
+                line_width += cluster_width;
+            }
+        }
+    }
+    // Keep trailing whitespace only as far as it fits
+    for grapheme in space.graphemes(true) {
+        let cluster_width = grapheme_width(grapheme);
+        if line_width + cluster_width > width {
+            break;
+        }
+        line.push_str(grapheme);
+        line_width += cluster_width;
+    }
+    lines.push(line);
+    return lines;
+}
+
+/// Split a paragraph into alternating runs of whitespace and words
+///
+/// Non-breaking spaces are part of the words they join
+fn tokenize(paragraph: &str) -> Vec<(bool, &str)> {
+    let mut tokens: Vec<(bool, &str)> = Vec::new();
+    let mut start = 0;
+    let mut in_space = false;
+    for (index, c) in paragraph.char_indices() {
+        let is_space = is_break_space(c);
+        if index > start && is_space != in_space {
+            tokens.push((in_space, &paragraph[start..index]));
+            start = index;
+        }
+        in_space = is_space;
+    }
+    if start < paragraph.len() {
+        tokens.push((in_space, &paragraph[start..]));
+    }
+    return tokens;
+}
+
+/// Whitespace lines may be broken at
+fn is_break_space(c: char) -> bool {
+    return c.is_whitespace() && !matches!(c, '\u{a0}' | '\u{2007}' | '\u{202f}');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrap_on_word_boundary() {
+        assert_eq!(
+            wrap("the quick brown fox\njumps over", 10),
+            vec!["the quick", "brown fox", "jumps over"]
+        );
+        assert_eq!(wrap("abcdefghij klm", 4), vec!["abcd", "efgh", "ij", "klm"]);
+        assert_eq!(wrap("héllo wörld", 5), vec!["héllo", "wörld"]);
+        assert_eq!(wrap("  indented  ", 10), vec!["  indented"]);
+        assert_eq!(wrap("你好世界 hi", 5), vec!["你好", "世界", "hi"]);
+        assert_eq!(
+            wrap("a 10\u{a0}km away", 6),
+            vec!["a", "10\u{a0}km", "away"]
+        );
+    }
+}
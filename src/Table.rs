@@ -0,0 +1,554 @@
+use crate::Style::{
+    display_width, edge_width, fit_glyph, horizontal_edge, truncate, vertical_edge,
+    widest_grapheme, width, BorderStyle, Style, StyleBlock,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Row index given to the style function for the header row
+pub const HEADER_ROW: usize = usize::MAX;
+
+/// Style of the cell at `(row, col)`
+///
+/// Data rows are counted from 0, the header row is `HEADER_ROW`.
+/// The text of the cell and its width are set by the table,
+/// everything else (padding, alignment, colors, decorators ...) comes from the returned block
+pub type StyleFunc = Arc<dyn Fn(usize, usize) -> StyleBlock + Send + Sync>;
+
+/// How the width of a column is chosen
+///
+/// Widths are measured in terminal columns and include the padding of the cells
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColumnWidth {
+    /// Width of the widest cell
+    Auto,
+    /// Exactly this width, wider cells are wrapped or truncated
+    ///
+    /// Widened when a cell could not fit its widest character
+    Fixed(usize),
+    /// Width of the widest cell, at least this width
+    Min(usize),
+    /// Width of the widest cell, at most this width
+    Max(usize),
+    /// Share of the room left by the other columns, weighted by the value
+    ///
+    /// Only used when the table width is set, sized like `Auto` otherwise
+    Flex(usize),
+}
+
+/// Table of text cells drawn with a border
+///
+/// # Example
+///
+/// ```
+/// # use ripgross::Style::*;
+/// # use ripgross::Table::*;
+/// let table = Table::new()
+///     .headers(&["Name", "Age"])
+///     .row(&["Alice", "31"])
+///     .row(&["Bob", "27"])
+///     .style_func(|row, _| match row {
+///         HEADER_ROW => StyleBlock::new().bold().padding(&[0, 1]),
+///         _ => StyleBlock::new().padding(&[0, 1]),
+///     });
+/// let rendered = table.render();
+/// assert_eq!(height(&rendered), 6);
+/// assert_eq!(width(&rendered), 15);
+/// ```
+#[derive(Clone)]
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+    border_style: BorderStyle,
+    border_decoration: Style,
+    border_top: bool,
+    border_bottom: bool,
+    border_left: bool,
+    border_right: bool,
+    border_header: bool,
+    border_column: bool,
+    border_row: bool,
+    column_widths: HashMap<usize, ColumnWidth>,
+    width: Option<usize>,
+    wrap: bool,
+    style_func: Option<StyleFunc>,
+}
+
+impl Table {
+    /// Create an empty table
+    ///
+    /// Every border is drawn except the separators between data rows
+    pub fn new() -> Table {
+        return Table {
+            headers: Vec::new(),
+            rows: Vec::new(),
+            border_style: BorderStyle::default(),
+            border_decoration: Style::new(),
+            border_top: true,
+            border_bottom: true,
+            border_left: true,
+            border_right: true,
+            border_header: true,
+            border_column: true,
+            border_row: false,
+            column_widths: HashMap::new(),
+            width: None,
+            wrap: true,
+            style_func: None,
+        };
+    }
+    /// Set the header row
+    pub fn headers<S: AsRef<str>>(mut self, headers: &[S]) -> Self {
+        self.headers = headers
+            .iter()
+            .map(|cell| cell.as_ref().to_owned())
+            .collect();
+        return self;
+    }
+    /// Append a data row
+    ///
+    /// Rows shorter than the others are completed with empty cells
+    pub fn row<S: AsRef<str>>(mut self, cells: &[S]) -> Self {
+        self.rows
+            .push(cells.iter().map(|cell| cell.as_ref().to_owned()).collect());
+        return self;
+    }
+    /// Append several data rows
+    pub fn rows<S: AsRef<str>>(mut self, rows: &[Vec<S>]) -> Self {
+        for cells in rows {
+            self = self.row(cells);
+        }
+        return self;
+    }
+    /// Remove every data row, headers are kept
+    pub fn clear_rows(mut self) -> Self {
+        self.rows.clear();
+        return self;
+    }
+    /// Set the style of every cell, see `StyleFunc`
+    ///
+    /// # Example
+    ///
+    /// Zebra striping
+    ///
+    /// ```
+    /// # use ripgross::Style::*;
+    /// # use ripgross::Table::*;
+    /// let table = Table::new().style_func(|row, _| match row {
+    ///     HEADER_ROW => StyleBlock::new().bold(),
+    ///     row if row % 2 == 0 => StyleBlock::new().background_rgb(40, 40, 40),
+    ///     _ => StyleBlock::new(),
+    /// });
+    /// ```
+    pub fn style_func(
+        mut self,
+        style_func: impl Fn(usize, usize) -> StyleBlock + Send + Sync + 'static,
+    ) -> Self {
+        self.style_func = Some(Arc::new(style_func));
+        return self;
+    }
+    /// Set the glyphs of the border, the middle joints are used for the inner separators
+    pub fn border_style(mut self, style: BorderStyle) -> Self {
+        self.border_style = style;
+        return self;
+    }
+    /// Set the colors and decorators of the border
+    pub fn border_decoration(mut self, style: &Style) -> Self {
+        self.border_decoration = style.clone();
+        return self;
+    }
+    pub fn set_border_top(mut self, enabled: bool) -> Self {
+        self.border_top = enabled;
+        return self;
+    }
+    pub fn set_border_bottom(mut self, enabled: bool) -> Self {
+        self.border_bottom = enabled;
+        return self;
+    }
+    pub fn set_border_left(mut self, enabled: bool) -> Self {
+        self.border_left = enabled;
+        return self;
+    }
+    pub fn set_border_right(mut self, enabled: bool) -> Self {
+        self.border_right = enabled;
+        return self;
+    }
+    /// Draw the separator under the header row, on by default
+    pub fn set_border_header(mut self, enabled: bool) -> Self {
+        self.border_header = enabled;
+        return self;
+    }
+    /// Draw the separators between columns, on by default
+    pub fn set_border_column(mut self, enabled: bool) -> Self {
+        self.border_column = enabled;
+        return self;
+    }
+    /// Draw the separators between data rows, off by default
+    pub fn set_border_row(mut self, enabled: bool) -> Self {
+        self.border_row = enabled;
+        return self;
+    }
+    /// Set how the width of column `col` is chosen, `ColumnWidth::Auto` by default
+    pub fn column_width(mut self, col: usize, width: ColumnWidth) -> Self {
+        self.column_widths.insert(col, width);
+        return self;
+    }
+    /// Set the width of the whole table, border included
+    ///
+    /// Flex columns share the room left by the other columns.
+    /// Without flex columns, the widest columns are shrunk until the table fits
+    pub fn set_width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        return self;
+    }
+    /// Wrap the text of cells too wide for their column, on by default
+    ///
+    /// When turned off, every line of such cells is truncated with "…"
+    pub fn set_wrap(mut self, enabled: bool) -> Self {
+        self.wrap = enabled;
+        return self;
+    }
+    /// Render the table to a string, without trailing newline
+    pub fn render(&self) -> String {
+        return self.render_lines().join("\n");
+    }
+    /// Render the table to a block, to be joined or placed with other blocks
+    pub fn to_block(&self) -> StyleBlock {
+        return StyleBlock::from_lines(self.render_lines());
+    }
+    fn render_lines(&self) -> Vec<String> {
+        let columns = self.column_count();
+        if columns == 0 {
+            return Vec::new();
+        }
+        let widths = self.column_sizes(columns);
+        let glyphs = &self.border_style;
+        let mut lines = Vec::new();
+        if self.border_top {
+            let top = self.horizontal_line(
+                (
+                    &glyphs.top_left,
+                    &glyphs.top,
+                    &glyphs.middle_top,
+                    &glyphs.top_right,
+                ),
+                &widths,
+            );
+            lines.push(top);
+        }
+        if !self.headers.is_empty() {
+            lines.extend(self.draw_row(HEADER_ROW, &self.headers, &widths));
+            if self.border_header {
+                let separator = self.horizontal_line(
+                    (
+                        &glyphs.middle_left,
+                        &glyphs.top,
+                        &glyphs.middle,
+                        &glyphs.middle_right,
+                    ),
+                    &widths,
+                );
+                lines.push(separator);
+            }
+        }
+        for (row, cells) in self.rows.iter().enumerate() {
+            if row > 0 && self.border_row {
+                let separator = self.horizontal_line(
+                    (
+                        &glyphs.middle_left,
+                        &glyphs.top,
+                        &glyphs.middle,
+                        &glyphs.middle_right,
+                    ),
+                    &widths,
+                );
+                lines.push(separator);
+            }
+            lines.extend(self.draw_row(row, cells, &widths));
+        }
+        if self.border_bottom {
+            let bottom = self.horizontal_line(
+                (
+                    &glyphs.bottom_left,
+                    &glyphs.bottom,
+                    &glyphs.middle_bottom,
+                    &glyphs.bottom_right,
+                ),
+                &widths,
+            );
+            lines.push(bottom);
+        }
+        return lines;
+    }
+    fn column_count(&self) -> usize {
+        return self
+            .rows
+            .iter()
+            .map(|cells| cells.len())
+            .chain(std::iter::once(self.headers.len()))
+            .max()
+            .unwrap_or(0);
+    }
+    /// Block of the cell at `(row, col)`, without its text
+    fn cell_style(&self, row: usize, col: usize) -> StyleBlock {
+        return match &self.style_func {
+            Some(style_func) => style_func(row, col),
+            None => StyleBlock::new(),
+        };
+    }
+    /// Every row with its index for the style function, header first
+    fn all_rows(&self) -> impl Iterator<Item = (usize, &Vec<String>)> {
+        let headers = if self.headers.is_empty() {
+            None
+        } else {
+            Some((HEADER_ROW, &self.headers))
+        };
+        return headers.into_iter().chain(self.rows.iter().enumerate());
+    }
+    /// Width of every column, cell padding included
+    fn column_sizes(&self, columns: usize) -> Vec<usize> {
+        // Narrowest column fitting the widest character of every cell
+        let mut minimum = vec![1; columns];
+        let mut natural = vec![1; columns];
+        for (row, cells) in self.all_rows() {
+            for col in 0..columns {
+                let frame = self.cell_style(row, col).get_horizontal_frame_size();
+                let text = cells.get(col).map(String::as_str).unwrap_or("");
+                minimum[col] = minimum[col].max(frame + widest_grapheme(text).max(1));
+                natural[col] = natural[col].max(frame + width(text));
+            }
+        }
+        let specs: Vec<ColumnWidth> = (0..columns)
+            .map(|col| *self.column_widths.get(&col).unwrap_or(&ColumnWidth::Auto))
+            .collect();
+        let mut sizes: Vec<usize> = specs
+            .iter()
+            .zip(natural.iter())
+            .map(|(spec, natural)| match *spec {
+                ColumnWidth::Auto | ColumnWidth::Flex(_) => *natural,
+                ColumnWidth::Fixed(size) => size,
+                ColumnWidth::Min(size) => size.max(*natural),
+                ColumnWidth::Max(size) => size.min(*natural),
+            })
+            .zip(minimum.iter())
+            .map(|(size, minimum)| size.max(*minimum))
+            .collect();
+        let available = match self.width {
+            Some(table_width) => table_width.saturating_sub(self.border_overhead(columns)),
+            None => return sizes,
+        };
+        let flex: Vec<(usize, usize)> = specs
+            .iter()
+            .enumerate()
+            .filter_map(|(col, spec)| match *spec {
+                ColumnWidth::Flex(weight) => Some((col, weight.max(1))),
+                _ => None,
+            })
+            .collect();
+        if !flex.is_empty() {
+            let fixed: usize = (0..columns)
+                .filter(|col| !flex.iter().any(|(flex_col, _)| flex_col == col))
+                .map(|col| sizes[col])
+                .sum();
+            let room = available.saturating_sub(fixed);
+            let total_weight: usize = flex.iter().map(|(_, weight)| weight).sum();
+            let mut given = 0;
+            for (i, (col, weight)) in flex.iter().enumerate() {
+                // The last flex column takes what rounding left over
+                let share = if i + 1 == flex.len() {
+                    room.saturating_sub(given)
+                } else {
+                    room * weight / total_weight
+                };
+                given += share;
+                sizes[*col] = share.max(minimum[*col]);
+            }
+            return sizes;
+        }
+        while sizes.iter().sum::<usize>() > available {
+            let widest = (0..columns)
+                .filter(|col| !matches!(specs[*col], ColumnWidth::Fixed(_)))
+                .filter(|col| sizes[*col] > minimum[*col])
+                .max_by_key(|col| (sizes[*col], *col));
+            match widest {
+                Some(col) => sizes[col] -= 1,
+                None => break,
+            }
+        }
+        return sizes;
+    }
+    /// Columns taken by the outer border and the column separators
+    fn border_overhead(&self, columns: usize) -> usize {
+        let glyphs = &self.border_style;
+        let mut overhead = 0;
+        if self.border_left {
+            overhead += edge_width(&glyphs.left);
+        }
+        if self.border_right {
+            overhead += edge_width(&glyphs.right);
+        }
+        if self.border_column {
+            overhead += edge_width(&glyphs.left) * (columns - 1);
+        }
+        return overhead;
+    }
+    /// Lines of a row, every cell padded to the height of the tallest one
+    fn draw_row(&self, row: usize, cells: &[String], widths: &[usize]) -> Vec<String> {
+        let blocks: Vec<StyleBlock> = widths
+            .iter()
+            .enumerate()
+            .map(|(col, column_width)| {
+                let text = cells.get(col).map(String::as_str).unwrap_or("");
+                self.cell_block(row, col, text, *column_width)
+            })
+            .collect();
+        let row_height = blocks
+            .iter()
+            .map(|block| block.get_height())
+            .max()
+            .unwrap_or(0);
+        let rendered: Vec<Vec<String>> = blocks
+            .into_iter()
+            .map(|block| {
+                let frame = block.get_vertical_border_size() + block.get_vertical_margins();
+                block
+                    .set_height(row_height.saturating_sub(frame))
+                    .render_lines()
+            })
+            .collect();
+        let glyphs = &self.border_style;
+        let separator_width = edge_width(&glyphs.left);
+        let mut lines = Vec::new();
+        for line in 0..row_height {
+            let mut text = String::new();
+            if self.border_left {
+                let edge = vertical_edge(&glyphs.left, line);
+                text.push_str(&self.paint(fit_glyph(&edge, " ", edge_width(&glyphs.left))));
+            }
+            for (col, cell) in rendered.iter().enumerate() {
+                if col > 0 && self.border_column {
+                    let edge = vertical_edge(&glyphs.left, line);
+                    text.push_str(&self.paint(fit_glyph(&edge, " ", separator_width)));
+                }
+                let cell_line = cell.get(line).map(String::as_str).unwrap_or("");
+                let cell_line = truncate(cell_line, widths[col], "");
+                text.push_str(&cell_line);
+                text.push_str(&" ".repeat(widths[col] - display_width(&cell_line)));
+            }
+            if self.border_right {
+                let edge = vertical_edge(&glyphs.right, line);
+                text.push_str(&self.paint(fit_glyph(&edge, " ", edge_width(&glyphs.right))));
+            }
+            lines.push(text);
+        }
+        return lines;
+    }
+    /// Block of a cell sized to its column
+    fn cell_block(&self, row: usize, col: usize, text: &str, column_width: usize) -> StyleBlock {
+        let style = self.cell_style(row, col);
+        let block_width =
+            column_width - style.get_horizontal_border_size() - style.get_horizontal_margins();
+        let text = if self.wrap {
+            text.to_owned()
+        } else {
+            let text_width = column_width - style.get_horizontal_frame_size();
+            truncate(text, text_width, "…")
+        };
+        return style.set_width(block_width).set_string(&text);
+    }
+    /// Top, bottom or separator line: `(left, edge, joint, right)` glyphs over the columns
+    fn horizontal_line(&self, glyphs: (&str, &str, &str, &str), widths: &[usize]) -> String {
+        let (left, edge, joint, right) = glyphs;
+        let mut line = String::new();
+        if self.border_left {
+            line.push_str(&fit_glyph(left, edge, edge_width(&self.border_style.left)));
+        }
+        for (col, column_width) in widths.iter().enumerate() {
+            if col > 0 && self.border_column {
+                line.push_str(&fit_glyph(joint, edge, edge_width(&self.border_style.left)));
+            }
+            line.push_str(&horizontal_edge(edge, *column_width));
+        }
+        if self.border_right {
+            line.push_str(&fit_glyph(
+                right,
+                edge,
+                edge_width(&self.border_style.right),
+            ));
+        }
+        return self.paint(line);
+    }
+    fn paint(&self, border: String) -> String {
+        return self.border_decoration.clone().render(&border);
+    }
+}
+
+impl Default for Table {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draw_table_with_separators() {
+        let table = Table::new()
+            .headers(&["a", "bb"])
+            .row(&["ccc"])
+            .row(&["d", "e"])
+            .set_border_row(true);
+        let expected = [
+            "┌───┬──┐",
+            "│a  │bb│",
+            "├───┼──┤",
+            "│ccc│  │",
+            "├───┼──┤",
+            "│d  │e │",
+            "└───┴──┘",
+        ];
+        assert_eq!(table.render(), expected.join("\n"));
+    }
+
+    #[test]
+    fn size_columns() {
+        let table = Table::new()
+            .row(&["hello world", "x"])
+            .column_width(0, ColumnWidth::Fixed(5))
+            .column_width(1, ColumnWidth::Flex(1))
+            .set_width(12);
+        let expected = [
+            "┌─────┬────┐",
+            "│hello│x   │",
+            "│world│    │",
+            "└─────┴────┘",
+        ];
+        assert_eq!(table.render(), expected.join("\n"));
+        let truncated = Table::new()
+            .row(&["hello world"])
+            .set_width(8)
+            .set_wrap(false)
+            .set_border_top(false)
+            .set_border_bottom(false);
+        assert_eq!(truncated.render(), "│hello…│");
+    }
+
+    #[test]
+    fn fit_wide_characters_in_narrow_columns() {
+        let table = Table::new()
+            .headers(&["h"])
+            .row(&["你好世界"])
+            .column_width(0, ColumnWidth::Fixed(3))
+            .style_func(|_, _| {
+                StyleBlock::new()
+                    .padding(&[0, 1])
+                    .margin(&[0, 1])
+                    .border_sides(&[true])
+            });
+        let rendered = table.render();
+        assert!(rendered.contains("│ │ 你 │ │"));
+        assert!(rendered.lines().all(|line| width(line) == 10));
+    }
+}
@@ -0,0 +1,344 @@
+pub use crate::List::{EnumeratorStyleFunc, ItemStyleFunc};
+use crate::Style::{display_width, Style, StyleBlock};
+use std::sync::Arc;
+
+/// Glyphs used to draw the branches of a tree
+///
+/// Branches are followed by a space, the vertical line is padded to the width of the branches
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BranchStyle {
+    /// Branch to a node followed by siblings
+    pub branch: String,
+    /// Branch to the last node
+    pub last_branch: String,
+    /// Line passing by the nodes of a sibling
+    pub vertical: String,
+}
+
+impl BranchStyle {
+    /// ├── └── Square turn to the last node
+    pub fn normal() -> BranchStyle {
+        return BranchStyle {
+            branch: "├──".to_owned(),
+            last_branch: "└──".to_owned(),
+            vertical: "│".to_owned(),
+        };
+    }
+    /// ├── ╰── Rounded turn to the last node
+    pub fn rounded() -> BranchStyle {
+        return BranchStyle {
+            last_branch: "╰──".to_owned(),
+            ..Self::normal()
+        };
+    }
+}
+
+impl Default for BranchStyle {
+    fn default() -> Self {
+        return Self::normal();
+    }
+}
+
+/// Node of a tree
+#[derive(Clone)]
+pub enum Node {
+    /// Text rendered with the item style
+    Text(String),
+    /// Block rendered as is, with its own style
+    Block(Box<StyleBlock>),
+    /// Subtree, its root is the node and its children are drawn under it
+    ///
+    /// The children of a subtree without root are drawn as siblings
+    Tree(Box<Tree>),
+}
+
+impl From<&str> for Node {
+    fn from(text: &str) -> Self {
+        return Node::Text(text.to_owned());
+    }
+}
+
+impl From<String> for Node {
+    fn from(text: String) -> Self {
+        return Node::Text(text);
+    }
+}
+
+impl From<StyleBlock> for Node {
+    fn from(block: StyleBlock) -> Self {
+        return Node::Block(Box::new(block));
+    }
+}
+
+impl From<Tree> for Node {
+    fn from(tree: Tree) -> Self {
+        return Node::Tree(Box::new(tree));
+    }
+}
+
+/// Tree of nodes drawn with branches
+///
+/// Nodes spanning several lines keep the branches of their siblings going along them.
+/// Subtrees take the branch and styles of their parent unless they set their own
+///
+/// # Example
+///
+/// ```
+/// # use ripgross::Tree::*;
+/// let tree = Tree::new()
+///     .root(".")
+///     .child("Cargo.toml")
+///     .child(Tree::new().root("src").children(&["lib.rs", "main.rs"]))
+///     .child("README.md");
+/// let expected = [
+///     ".",
+///     "├── Cargo.toml",
+///     "├── src",
+///     "│   ├── lib.rs",
+///     "│   └── main.rs",
+///     "└── README.md",
+/// ];
+/// assert_eq!(tree.render(), expected.join("\n"));
+/// ```
+#[derive(Clone)]
+pub struct Tree {
+    root: Option<Box<Node>>,
+    children: Vec<Node>,
+    branch_style: Option<BranchStyle>,
+    root_style: Option<StyleBlock>,
+    enumerator_style: Option<EnumeratorStyleFunc>,
+    item_style: Option<ItemStyleFunc>,
+}
+
+/// Branch and styles in effect for the children of a tree
+#[derive(Clone)]
+struct Settings {
+    branch_style: BranchStyle,
+    enumerator_style: Option<EnumeratorStyleFunc>,
+    item_style: Option<ItemStyleFunc>,
+}
+
+impl Tree {
+    /// Create an empty tree without root
+    pub fn new() -> Tree {
+        return Tree {
+            root: None,
+            children: Vec::new(),
+            branch_style: None,
+            root_style: None,
+            enumerator_style: None,
+            item_style: None,
+        };
+    }
+    /// Set the root, drawn above the children without branch
+    pub fn root(mut self, root: impl Into<Node>) -> Self {
+        self.root = Some(Box::new(root.into()));
+        return self;
+    }
+    /// Append a child: text, a block or a subtree
+    pub fn child(mut self, child: impl Into<Node>) -> Self {
+        self.children.push(child.into());
+        return self;
+    }
+    /// Append several text children
+    pub fn children<S: AsRef<str>>(mut self, children: &[S]) -> Self {
+        for child in children {
+            self = self.child(child.as_ref());
+        }
+        return self;
+    }
+    /// Set the glyphs of the branches, `BranchStyle::normal()` by default
+    pub fn branch_style(mut self, style: BranchStyle) -> Self {
+        self.branch_style = Some(style);
+        return self;
+    }
+    /// Set the style of the root
+    pub fn root_style(mut self, style: &StyleBlock) -> Self {
+        self.root_style = Some(style.clone());
+        return self;
+    }
+    /// Set the style of every branch
+    pub fn enumerator_style(self, style: &Style) -> Self {
+        let style = style.clone();
+        return self.enumerator_style_func(move |_| style.clone());
+    }
+    /// Set the style of the branch at each index among the children
+    pub fn enumerator_style_func(
+        mut self,
+        style_func: impl Fn(usize) -> Style + Send + Sync + 'static,
+    ) -> Self {
+        self.enumerator_style = Some(Arc::new(style_func));
+        return self;
+    }
+    /// Set the style of every text child
+    pub fn item_style(self, style: &StyleBlock) -> Self {
+        let style = style.clone();
+        return self.item_style_func(move |_| style.clone());
+    }
+    /// Set the style of the text child at each index among the children
+    pub fn item_style_func(
+        mut self,
+        style_func: impl Fn(usize) -> StyleBlock + Send + Sync + 'static,
+    ) -> Self {
+        self.item_style = Some(Arc::new(style_func));
+        return self;
+    }
+    /// Render the tree to a string, without trailing newline
+    pub fn render(&self) -> String {
+        return self.render_lines().join("\n");
+    }
+    /// Render the tree to a block, to be joined or placed with other blocks
+    pub fn to_block(&self) -> StyleBlock {
+        return StyleBlock::from_lines(self.render_lines());
+    }
+    fn render_lines(&self) -> Vec<String> {
+        let settings = Settings {
+            branch_style: BranchStyle::default(),
+            enumerator_style: None,
+            item_style: None,
+        };
+        return self.draw(&settings);
+    }
+    /// Settings of this tree, falling back to those of its parent
+    fn settings(&self, parent: &Settings) -> Settings {
+        return Settings {
+            branch_style: self
+                .branch_style
+                .clone()
+                .unwrap_or_else(|| parent.branch_style.clone()),
+            enumerator_style: self
+                .enumerator_style
+                .clone()
+                .or_else(|| parent.enumerator_style.clone()),
+            item_style: self
+                .item_style
+                .clone()
+                .or_else(|| parent.item_style.clone()),
+        };
+    }
+    /// Root and children lines
+    fn draw(&self, parent: &Settings) -> Vec<String> {
+        let settings = self.settings(parent);
+        let mut lines = Vec::new();
+        if let Some(root) = &self.root {
+            let style = self.root_style.clone().unwrap_or_default();
+            lines.extend(node_lines(root, style, &settings));
+        }
+        lines.extend(self.draw_children(&settings));
+        return lines;
+    }
+    /// Children lines with their branches, children of rootless subtrees included
+    fn draw_children(&self, settings: &Settings) -> Vec<String> {
+        let mut siblings: Vec<(&Node, Settings)> = Vec::new();
+        self.collect_siblings(settings, &mut siblings);
+        let glyphs = &settings.branch_style;
+        let branch_width = display_width(&glyphs.branch).max(display_width(&glyphs.last_branch));
+        let mut lines = Vec::new();
+        for (index, (node, node_settings)) in siblings.iter().enumerate() {
+            let is_last = index + 1 == siblings.len();
+            let enumerator_style = match &settings.enumerator_style {
+                Some(style_func) => style_func(index),
+                None => Style::new(),
+            };
+            let branch = if is_last {
+                &glyphs.last_branch
+            } else {
+                &glyphs.branch
+            };
+            let mut prefix = enumerator_style.clone().render(branch);
+            prefix.push_str(&" ".repeat(branch_width + 1 - display_width(branch)));
+            let indent = if is_last {
+                " ".repeat(branch_width + 1)
+            } else {
+                let mut indent = enumerator_style.render(&glyphs.vertical);
+                indent.push_str(
+                    &" ".repeat((branch_width + 1).saturating_sub(display_width(&glyphs.vertical))),
+                );
+                indent
+            };
+            let item_style = match &node_settings.item_style {
+                Some(style_func) => style_func(index),
+                None => StyleBlock::new(),
+            };
+            for (row, line) in node_lines(node, item_style, node_settings)
+                .into_iter()
+                .enumerate()
+            {
+                if row == 0 {
+                    lines.push(format!("{}{}", prefix, line));
+                } else {
+                    lines.push(format!("{}{}", indent, line));
+                }
+            }
+        }
+        return lines;
+    }
+    /// Children drawn at this level, with the settings each of them is drawn with
+    fn collect_siblings<'a>(
+        &'a self,
+        settings: &Settings,
+        siblings: &mut Vec<(&'a Node, Settings)>,
+    ) {
+        for child in self.children.iter() {
+            match child {
+                Node::Tree(tree) if tree.root.is_none() => {
+                    tree.collect_siblings(&tree.settings(settings), siblings);
+                }
+                _ => siblings.push((child, settings.clone())),
+            }
+        }
+    }
+}
+
+impl Default for Tree {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+/// Lines of a node, a subtree draws its children under its root
+fn node_lines(node: &Node, style: StyleBlock, settings: &Settings) -> Vec<String> {
+    return match node {
+        Node::Text(text) => style.set_string(text).render_lines(),
+        Node::Block(block) => block.render_lines(),
+        Node::Tree(tree) => {
+            let mut tree = tree.clone();
+            if tree.root_style.is_none() {
+                tree.root_style = Some(style);
+            }
+            tree.draw(settings)
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draw_multiline_nodes() {
+        let tree = Tree::new()
+            .branch_style(BranchStyle::rounded())
+            .child("one\ntwo")
+            .child(Tree::new().root("sub").child("leaf"))
+            .child(Tree::new().children(&["flat"]));
+        let expected = ["├── one", "│   two", "├── sub", "│   ╰── leaf", "╰── flat"];
+        assert_eq!(tree.render(), expected.join("\n"));
+    }
+
+    #[test]
+    fn style_by_index() {
+        let renderer = crate::Style::Renderer::new(std::io::sink());
+        renderer.set_color_profile(crate::Style::ColorProfile::TrueColor);
+        let bold = Style::new().bold().renderer(&renderer);
+        let tree = Tree::new()
+            .children(&["a", "b", "c"])
+            .enumerator_style_func(move |index| match index {
+                1 => bold.clone(),
+                _ => Style::new(),
+            })
+            .item_style_func(|index| StyleBlock::new().padding_left(index));
+        let expected = ["├── a", "\u{1b}[1m├──\u{1b}[0m  b", "└──   c"];
+        assert_eq!(tree.render(), expected.join("\n"));
+    }
+}
@@ -1,10 +1,18 @@
 #![crate_name = "ripgross"]
 #![crate_type = "rlib"]
+#![allow(clippy::needless_return)]
 pub fn add(left: usize, right: usize) -> usize {
     left + right
 }
 
+#[allow(non_snake_case)]
+pub mod List;
+#[allow(non_snake_case)]
 pub mod Style;
+#[allow(non_snake_case)]
+pub mod Table;
+#[allow(non_snake_case)]
+pub mod Tree;
 
 #[cfg(test)]
 mod tests {
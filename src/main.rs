@@ -1,3 +1,4 @@
+#![allow(clippy::needless_return)]
 use ripgross::Style::*;
 
 fn main() {
@@ -52,6 +53,7 @@ Sed est ex, volutpat id sapien sed, pretium ornare tellus. Quisque laoreet, null
                 .render_to_block("
 Phasellus fringilla bibendum condimentum. Cras eros quam, viverra nec finibus ac, aliquet eu purus. Cras non fermentum lorem. Phasellus viverra eros mi, eu vestibulum nisi aliquam ac. Morbi pharetra urna ut cursus sodales. In dapibus enim id laoreet tincidunt. Mauris et ante velit. Lorem ipsum dolor sit amet, consectetur adipiscing elit. Fusce lobortis mauris interdum laoreet ultricies. Nulla a eros convallis, auctor nisi non, lobortis neque. Nullam a dui a quam tempor congue. Vestibulum sit amet nisl lacus. Nulla a rhoncus neque.
 ")
+            .set_width(80)
             .padding_top(4)
             .padding_left(8)
             .padding_right(8)
@@ -70,6 +72,7 @@ Phasellus fringilla bibendum condimentum. Cras eros quam, viverra nec finibus ac
                     .bold()
                     .strikethrough()
                     .render_to_block("She literature discovered increasing how diminution understood. Though and highly the enough county for man. Of it up he still court alone widow seems. Suspected he remainder rapturous my sweetness. All vanity regard sudden nor simple can. World mrs and vexed china since after often.")
+            .set_width(80)
             .align_end()
                 )
             )
@@ -80,7 +83,7 @@ fn test3() {
     println!("{}{}",Style::new()
         .blink()
         .reverse()
-        .render_to_block("Use securing confined his shutters. Delightful as he it acceptance an solicitude discretion reasonably. Carriage we husbands advanced an perceive greatest. Totally dearest expense on demesne ye he. Curiosity excellent commanded in me. Unpleasing impression themselves to at assistance acceptance my or. On consider laughter civility offended oh.").finalize()
+        .render_to_block("Use securing confined his shutters. Delightful as he it acceptance an solicitude discretion reasonably. Carriage we husbands advanced an perceive greatest. Totally dearest expense on demesne ye he. Curiosity excellent commanded in me. Unpleasing impression themselves to at assistance acceptance my or. On consider laughter civility offended oh.").set_width(80).finalize()
         ,
         Style::new()
         .underline()